@@ -0,0 +1,846 @@
+use std::path::Path;
+
+use anyhow::{anyhow, Context as _, Result};
+use serde_json::json;
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signature},
+    signer::Signer,
+    transaction::Transaction,
+};
+
+use nft_program_client::{self as client, CollectionInfo, NftInfo, PoolItem};
+
+use crate::{
+    rpc::RpcClient,
+    spec::{self, CollectionSpec, Manifest, RoyaltySpec, TemplateSpec},
+};
+
+pub struct Context {
+    pub rpc: RpcClient,
+    pub payer: Keypair,
+}
+
+fn expand_home(path: &str) -> String {
+    match (path.strip_prefix("~/"), std::env::var("HOME")) {
+        (Some(rest), Ok(home)) => format!("{home}/{rest}"),
+        _ => path.to_string(),
+    }
+}
+
+pub fn load_keypair(path: &str) -> Result<Keypair> {
+    let path = expand_home(path);
+    read_keypair_file(&path).map_err(|err| anyhow!("failed to read keypair {path}: {err}"))
+}
+
+impl Context {
+    pub fn new(url: &str, keypair: &str) -> Result<Self> {
+        Ok(Self {
+            rpc: RpcClient::new(url),
+            payer: load_keypair(keypair)?,
+        })
+    }
+
+    /// Picks the lite variant of `verify_collection` when the NFT has no `NftInfo` account.
+    pub fn verify_instruction(&self, mint: &Pubkey, collection: &Pubkey) -> Result<Instruction> {
+        let has_nft_info = self.rpc.get_account_data(&client::find_nft_info(mint).0)?.is_some();
+        Ok(if has_nft_info {
+            client::verify_collection(self.payer.pubkey(), *mint, *collection)
+        } else {
+            client::verify_collection_lite(self.payer.pubkey(), *mint, *collection)
+        })
+    }
+
+    pub fn token_program_of(&self, mint: &Pubkey) -> Result<Pubkey> {
+        self.rpc
+            .get_account_owner(mint)?
+            .ok_or_else(|| anyhow!("mint {mint} does not exist"))
+    }
+
+    /// Creator wallets of a Token Metadata NFT in metadata order, who receive sale royalties.
+    pub fn creators_of(&self, mint: &Pubkey) -> Result<Vec<Pubkey>> {
+        let data = self
+            .rpc
+            .get_account_data(&client::find_metadata(mint).0)?
+            .ok_or_else(|| anyhow!("{mint} has no Token Metadata account"))?;
+        Ok(client::decode_metadata(&data)?
+            .creators
+            .iter()
+            .flatten()
+            .map(|creator| creator.address)
+            .collect())
+    }
+
+    pub fn collection_info(&self, collection: &Pubkey) -> Result<CollectionInfo> {
+        let address = client::find_collection_info(collection).0;
+        let data = self
+            .rpc
+            .get_account_data(&address)?
+            .ok_or_else(|| anyhow!("no CollectionInfo account at {address}"))?;
+        Ok(client::decode_collection_info(&data)?)
+    }
+
+    /// Reads the staking pool of `collection` to find its reward mint and how rewards are paid.
+    pub fn reward_options(&self, collection: &Pubkey) -> Result<client::RewardOptions> {
+        let pool = client::find_staking_pool(collection).0;
+        let data = self
+            .rpc
+            .get_account_data(&pool)?
+            .ok_or_else(|| anyhow!("collection {collection} has no staking pool"))?;
+        let reward_mint = client::decode_staking_pool(&data)?.reward_mint;
+
+        // SPL mints start with their mint authority as a `COption<Pubkey>`: a 4-byte tag, then the key.
+        let mint_data = self
+            .rpc
+            .get_account_data(&reward_mint)?
+            .ok_or_else(|| anyhow!("reward mint {reward_mint} does not exist"))?;
+        let pool_mints =
+            mint_data.get(..4) == Some(&[1, 0, 0, 0][..]) && mint_data.get(4..36) == Some(pool.as_ref());
+
+        Ok(client::RewardOptions {
+            reward_mint,
+            reward_token_program: self.token_program_of(&reward_mint)?,
+            from_vault: !pool_mints,
+        })
+    }
+
+    pub fn send(&self, instructions: &[Instruction], extra_signers: &[&Keypair]) -> Result<Signature> {
+        let mut signers: Vec<&Keypair> = vec![&self.payer];
+        signers.extend_from_slice(extra_signers);
+
+        let blockhash = self.rpc.get_latest_blockhash()?;
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.payer.pubkey()),
+            &signers,
+            blockhash,
+        );
+        self.rpc.send_and_confirm_transaction(&transaction)
+    }
+}
+
+pub fn collection_info_json(info: &CollectionInfo) -> serde_json::Value {
+    json!({
+        "mint": info.mint.to_string(),
+        "name": info.name,
+        "symbol": info.symbol,
+        "uri": info.uri,
+        "creator": info.creator.to_string(),
+        "number_of_nfts": info.number_of_nfts,
+        "created_at": info.created_at,
+        "standard": format!("{:?}", info.standard),
+        "merkle_tree": info.merkle_tree.map(|tree| tree.to_string()),
+        "compressed_leaf_count": info.compressed_leaf_count,
+        "parent": info.parent.map(|parent| parent.to_string()),
+        "seller_fee_basis_points": info.seller_fee_basis_points,
+        "creators": info
+            .creators
+            .iter()
+            .map(|creator| json!({ "address": creator.address.to_string(), "share": creator.share }))
+            .collect::<Vec<_>>(),
+        "enforce_royalties": info.enforce_royalties,
+        "soulbound": info.soulbound,
+    })
+}
+
+pub fn nft_info_json(info: &NftInfo) -> serde_json::Value {
+    json!({
+        "mint": info.mint.to_string(),
+        "collection_mint": info.collection_mint.to_string(),
+        "name": info.name,
+        "symbol": info.symbol,
+        "uri": info.uri,
+        "owner": info.owner.to_string(),
+        "verified": info.verified,
+        "minted_at": info.minted_at,
+        "frozen_since": info.frozen_since,
+        "uses": info.uses.map(|uses| json!({
+            "method": format!("{:?}", uses.use_method),
+            "total": uses.total,
+            "remaining": info.remaining_uses,
+        })),
+        "use_authority": info.use_authority.map(|approval| approval.authority.to_string()),
+    })
+}
+
+/// Kind of collection the `create-collection` command creates.
+pub enum CollectionKind {
+    Metaplex { provenance_hash: Option<[u8; 32]>, parent: Option<Pubkey> },
+    Token2022Group { max_size: u64 },
+    Core,
+}
+
+pub fn create_collection(
+    ctx: &Context,
+    spec_path: &Path,
+    mint_keypair: Option<&str>,
+    kind: CollectionKind,
+) -> Result<()> {
+    let spec: CollectionSpec = spec::load(spec_path)?;
+    let mint = match mint_keypair {
+        Some(path) => load_keypair(path)?,
+        None => Keypair::new(),
+    };
+
+    let collection_data = spec.to_collection_data()?;
+    let ix = match kind {
+        CollectionKind::Token2022Group { max_size } => {
+            client::create_collection_token_2022(ctx.payer.pubkey(), mint.pubkey(), collection_data, max_size)
+        }
+        CollectionKind::Core => client::create_collection_core(ctx.payer.pubkey(), mint.pubkey(), collection_data),
+        CollectionKind::Metaplex { provenance_hash, parent: None } => {
+            client::create_collection(ctx.payer.pubkey(), mint.pubkey(), collection_data, provenance_hash)
+        }
+        CollectionKind::Metaplex { provenance_hash, parent: Some(parent) } => {
+            client::create_child_collection(ctx.payer.pubkey(), mint.pubkey(), collection_data, provenance_hash, parent)
+        }
+    };
+    let signature = ctx.send(&[ix], &[&mint])?;
+
+    println!("Collection mint: {}", mint.pubkey());
+    println!("Signature: {signature}");
+    Ok(())
+}
+
+/// Kind of NFT the `mint` command creates.
+pub enum MintStandard {
+    Metaplex,
+    Token2022,
+    Core,
+    Programmable { rule_set: Option<Pubkey> },
+    /// Token Metadata NFTs frozen in the payer's wallet.
+    Soulbound,
+}
+
+pub fn mint(
+    ctx: &Context,
+    collection: &Pubkey,
+    manifest_path: &Path,
+    verify: bool,
+    lite: bool,
+    standard: MintStandard,
+) -> Result<()> {
+    let manifest: Manifest = spec::load(manifest_path)?;
+    // Soulbound collections reject items minted without their `SoulboundRecord`.
+    let standard = match standard {
+        MintStandard::Metaplex if ctx.collection_info(collection)?.soulbound => MintStandard::Soulbound,
+        standard => standard,
+    };
+
+    for (index, item) in manifest.nfts.iter().enumerate() {
+        let mint = Keypair::new();
+        let nft_data = item.to_nft_data()?;
+        let ix = match standard {
+            MintStandard::Token2022 => {
+                client::mint_nft_token_2022(ctx.payer.pubkey(), mint.pubkey(), *collection, nft_data, lite)
+            }
+            MintStandard::Core => {
+                client::mint_nft_core(ctx.payer.pubkey(), mint.pubkey(), *collection, nft_data, lite)
+            }
+            MintStandard::Programmable { rule_set } => {
+                client::mint_pnft(ctx.payer.pubkey(), mint.pubkey(), *collection, nft_data, rule_set, lite)
+            }
+            MintStandard::Metaplex if lite => {
+                client::mint_nft_lite(ctx.payer.pubkey(), mint.pubkey(), *collection, nft_data)
+            }
+            MintStandard::Metaplex => client::mint_nft(ctx.payer.pubkey(), mint.pubkey(), *collection, nft_data),
+            MintStandard::Soulbound => {
+                client::mint_soulbound_nft(ctx.payer.pubkey(), mint.pubkey(), *collection, nft_data)
+            }
+        };
+        let signature = ctx
+            .send(&[ix], &[&mint])
+            .with_context(|| format!("failed to mint item {index} ({})", item.name))?;
+        println!("Minted {} as {} ({signature})", item.name, mint.pubkey());
+
+        if verify {
+            let ix = ctx.verify_instruction(&mint.pubkey(), collection)?;
+            let signature = ctx.send(&[ix], &[])?;
+            println!("Verified {} ({signature})", mint.pubkey());
+        }
+    }
+    Ok(())
+}
+
+pub fn create_tree(
+    ctx: &Context,
+    collection: &Pubkey,
+    max_depth: u32,
+    max_buffer_size: u32,
+    canopy_depth: u32,
+) -> Result<()> {
+    let tree = Keypair::new();
+    let space = client::merkle_tree_account_size(max_depth, max_buffer_size, canopy_depth);
+    let lamports = ctx.rpc.get_minimum_balance_for_rent_exemption(space)?;
+
+    let instructions = [
+        client::allocate_merkle_tree(ctx.payer.pubkey(), tree.pubkey(), lamports, space),
+        client::create_compressed_tree(ctx.payer.pubkey(), *collection, tree.pubkey(), max_depth, max_buffer_size),
+    ];
+    let signature = ctx.send(&instructions, &[&tree])?;
+
+    println!("Merkle tree: {} ({space} bytes, {lamports} lamports)", tree.pubkey());
+    println!("Signature: {signature}");
+    Ok(())
+}
+
+pub fn mint_compressed(ctx: &Context, collection: &Pubkey, manifest_path: &Path, owner: Option<Pubkey>) -> Result<()> {
+    let manifest: Manifest = spec::load(manifest_path)?;
+
+    let address = client::find_collection_info(collection).0;
+    let data = ctx
+        .rpc
+        .get_account_data(&address)?
+        .ok_or_else(|| anyhow!("no CollectionInfo account at {address}"))?;
+    let merkle_tree = client::decode_collection_info(&data)?
+        .merkle_tree
+        .ok_or_else(|| anyhow!("collection {collection} has no Merkle tree, run create-tree first"))?;
+    let owner = owner.unwrap_or_else(|| ctx.payer.pubkey());
+
+    for (index, item) in manifest.nfts.iter().enumerate() {
+        let ix = client::mint_compressed_nft(ctx.payer.pubkey(), owner, *collection, merkle_tree, item.to_nft_data()?);
+        let signature = ctx
+            .send(&[ix], &[])
+            .with_context(|| format!("failed to mint item {index} ({})", item.name))?;
+        println!("Minted compressed {} to {owner} ({signature})", item.name);
+    }
+    Ok(())
+}
+
+pub fn verify(ctx: &Context, collection: &Pubkey, mints: &[Pubkey]) -> Result<()> {
+    for mint in mints {
+        let ix = ctx.verify_instruction(mint, collection)?;
+        let signature = ctx.send(&[ix], &[])?;
+        println!("Verified {mint} ({signature})");
+    }
+    Ok(())
+}
+
+pub fn unverify(ctx: &Context, collection: &Pubkey, mints: &[Pubkey]) -> Result<()> {
+    for mint in mints {
+        let lite = ctx.rpc.get_account_data(&client::find_nft_info(mint).0)?.is_none();
+        let ix = if lite {
+            client::unverify_collection_lite(ctx.payer.pubkey(), *mint, *collection)
+        } else {
+            client::unverify_collection(ctx.payer.pubkey(), *mint, *collection)
+        };
+        let signature = ctx.send(&[ix], &[])?;
+        println!("Unverified {mint} ({signature})");
+    }
+    Ok(())
+}
+
+pub fn reconcile_size(ctx: &Context, collection: &Pubkey) -> Result<()> {
+    let ix = client::reconcile_collection_size(*collection);
+    let signature = ctx.send(&[ix], &[])?;
+
+    let address = client::find_collection_info(collection).0;
+    let data = ctx
+        .rpc
+        .get_account_data(&address)?
+        .ok_or_else(|| anyhow!("no CollectionInfo account at {address}"))?;
+    let size = client::decode_collection_info(&data)?.number_of_nfts;
+    println!("Collection size: {size} ({signature})");
+    Ok(())
+}
+
+/// Makes the royalties in `spec_path` the defaults of `collection`, enforced when the spec says so.
+pub fn set_soulbound(ctx: &Context, collection: &Pubkey, soulbound: bool) -> Result<()> {
+    let ix = client::set_soulbound_policy(ctx.payer.pubkey(), *collection, soulbound);
+    let signature = ctx.send(&[ix], &[])?;
+    println!("Soulbound items required: {soulbound} ({signature})");
+    Ok(())
+}
+
+pub fn set_royalties(ctx: &Context, collection: &Pubkey, spec_path: &Path) -> Result<()> {
+    let royalties: RoyaltySpec = spec::load(spec_path)?;
+    let ix = client::set_royalty_policy(
+        ctx.payer.pubkey(),
+        *collection,
+        royalties.seller_fee_basis_points,
+        royalties.to_creator_data()?,
+        royalties.enforce,
+    );
+    let signature = ctx.send(&[ix], &[])?;
+    println!(
+        "Royalty policy of {collection} set to {} bps{} ({signature})",
+        royalties.seller_fee_basis_points,
+        if royalties.enforce { ", enforced" } else { "" },
+    );
+    Ok(())
+}
+
+/// Consumes one use of each NFT, signed by the payer as holder or, with `owner`, as delegate.
+pub fn use_nft(ctx: &Context, mints: &[Pubkey], owner: Option<Pubkey>) -> Result<()> {
+    let owner = owner.unwrap_or_else(|| ctx.payer.pubkey());
+    for mint in mints {
+        let data = ctx
+            .rpc
+            .get_account_data(&client::find_metadata(mint).0)?
+            .ok_or_else(|| anyhow!("{mint} has no Token Metadata account"))?;
+        let collection_mint = client::decode_metadata(&data)?
+            .collection
+            .as_ref()
+            .filter(|collection| collection.verified)
+            .map(|collection| collection.key);
+
+        let ix = client::use_nft(ctx.payer.pubkey(), owner, *mint, collection_mint);
+        let signature = ctx.send(&[ix], &[])?;
+        match ctx.rpc.get_account_data(&client::find_nft_info(mint).0)? {
+            Some(data) => {
+                let remaining = client::decode_nft_info(&data)?.remaining_uses;
+                println!("Used {mint}, {remaining} uses left ({signature})");
+            }
+            None => println!("Used {mint} for the last time, it was burned ({signature})"),
+        }
+    }
+    Ok(())
+}
+
+pub fn set_use_authority(ctx: &Context, mints: &[Pubkey], authority: Option<Pubkey>) -> Result<()> {
+    for mint in mints {
+        let ix = client::set_use_authority(ctx.payer.pubkey(), *mint, authority);
+        let signature = ctx.send(&[ix], &[])?;
+        match authority {
+            Some(authority) => println!("Approved {authority} to use {mint} ({signature})"),
+            None => println!("Revoked the use authority of {mint} ({signature})"),
+        }
+    }
+    Ok(())
+}
+
+pub fn revoke_soulbound(ctx: &Context, collection: &Pubkey, owner: &Pubkey, mints: &[Pubkey]) -> Result<()> {
+    for mint in mints {
+        let lite = ctx.rpc.get_account_data(&client::find_nft_info(mint).0)?.is_none();
+        let ix = client::revoke_soulbound(ctx.payer.pubkey(), *owner, *mint, *collection, lite);
+        let signature = ctx.send(&[ix], &[])?;
+        println!("Revoked soulbound {mint} from {owner} ({signature})");
+    }
+    Ok(())
+}
+
+/// Token program and whether the NFT freezes through a Token Metadata master edition.
+fn nft_token_program(token_2022: bool) -> (Pubkey, bool) {
+    if token_2022 {
+        (client::anchor_spl::token_2022::ID, false)
+    } else {
+        (client::anchor_spl::token::ID, true)
+    }
+}
+
+pub fn set_frozen(ctx: &Context, mints: &[Pubkey], token_2022: bool, frozen: bool) -> Result<()> {
+    let (token_program, token_metadata) = nft_token_program(token_2022);
+
+    for mint in mints {
+        let lite = ctx.rpc.get_account_data(&client::find_nft_info(mint).0)?.is_none();
+        let ix = if frozen {
+            client::freeze_nft(ctx.payer.pubkey(), *mint, token_program, token_metadata, lite)
+        } else {
+            client::thaw_nft(ctx.payer.pubkey(), *mint, token_program, token_metadata, lite)
+        };
+        let signature = ctx.send(&[ix], &[])?;
+        println!("{} {mint} ({signature})", if frozen { "Froze" } else { "Thawed" });
+    }
+    Ok(())
+}
+
+pub fn rent_out(
+    ctx: &Context,
+    mint: &Pubkey,
+    renter_keypair: &str,
+    duration: i64,
+    fee: u64,
+    token_2022: bool,
+) -> Result<()> {
+    let (token_program, token_metadata) = nft_token_program(token_2022);
+    let renter = load_keypair(renter_keypair)?;
+    let ix = client::rent_out(ctx.payer.pubkey(), renter.pubkey(), *mint, token_program, token_metadata, duration, fee);
+    let signature = ctx.send(&[ix], &[&renter])?;
+
+    println!("Rental agreement: {}", client::find_rental_agreement(mint).0);
+    println!("Signature: {signature}");
+    Ok(())
+}
+
+pub fn reclaim(ctx: &Context, mints: &[Pubkey], token_2022: bool) -> Result<()> {
+    let (token_program, token_metadata) = nft_token_program(token_2022);
+    for mint in mints {
+        let data = ctx
+            .rpc
+            .get_account_data(&client::find_rental_agreement(mint).0)?
+            .ok_or_else(|| anyhow!("{mint} is not rented out"))?;
+        let rental = client::decode_rental_agreement(&data)?;
+        let ix = client::reclaim(ctx.payer.pubkey(), rental.owner, rental.renter, *mint, token_program, token_metadata);
+        let signature = ctx.send(&[ix], &[])?;
+        println!("Returned {mint} to {} ({signature})", rental.owner);
+    }
+    Ok(())
+}
+
+pub fn list(ctx: &Context, mint: &Pubkey, price: u64) -> Result<()> {
+    let ix = client::list_nft(ctx.payer.pubkey(), *mint, price);
+    let signature = ctx.send(&[ix], &[])?;
+
+    println!("Listing: {}", client::find_listing(mint).0);
+    println!("Signature: {signature}");
+    Ok(())
+}
+
+pub fn cancel_listing(ctx: &Context, mints: &[Pubkey]) -> Result<()> {
+    for mint in mints {
+        let ix = client::cancel_listing(ctx.payer.pubkey(), *mint, ctx.token_program_of(mint)?);
+        let signature = ctx.send(&[ix], &[])?;
+        println!("Canceled listing of {mint} ({signature})");
+    }
+    Ok(())
+}
+
+pub fn buy(ctx: &Context, mint: &Pubkey, price: u64) -> Result<()> {
+    let data = ctx
+        .rpc
+        .get_account_data(&client::find_listing(mint).0)?
+        .ok_or_else(|| anyhow!("{mint} is not listed"))?;
+    let listing = client::decode_listing(&data)?;
+
+    let creators = ctx.creators_of(mint)?;
+    let lite = ctx.rpc.get_account_data(&client::find_nft_info(mint).0)?.is_none();
+    let ix = client::buy_nft(ctx.payer.pubkey(), listing.seller, *mint, ctx.token_program_of(mint)?, &creators, price, lite);
+    let signature = ctx.send(&[ix], &[])?;
+    println!("Bought {mint} from {} for {price} lamports ({signature})", listing.seller);
+    Ok(())
+}
+
+pub fn bid(ctx: &Context, target: &Pubkey, price: u64, collection_wide: bool) -> Result<()> {
+    let ix = client::place_bid(ctx.payer.pubkey(), *target, price, collection_wide);
+    let signature = ctx.send(&[ix], &[])?;
+
+    println!("Bid: {}", client::find_bid(target, &ctx.payer.pubkey()).0);
+    println!("Signature: {signature}");
+    Ok(())
+}
+
+pub fn cancel_bids(ctx: &Context, targets: &[Pubkey]) -> Result<()> {
+    for target in targets {
+        let ix = client::cancel_bid(ctx.payer.pubkey(), *target);
+        let signature = ctx.send(&[ix], &[])?;
+        println!("Canceled bid on {target} ({signature})");
+    }
+    Ok(())
+}
+
+pub fn accept_bid(ctx: &Context, mint: &Pubkey, bidder: &Pubkey, collection: Option<&Pubkey>, price: u64) -> Result<()> {
+    let target = collection.unwrap_or(mint);
+    let creators = ctx.creators_of(mint)?;
+    let lite = ctx.rpc.get_account_data(&client::find_nft_info(mint).0)?.is_none();
+    let ix = client::accept_bid(
+        ctx.payer.pubkey(),
+        *bidder,
+        *target,
+        *mint,
+        ctx.token_program_of(mint)?,
+        &creators,
+        price,
+        lite,
+    );
+    let signature = ctx.send(&[ix], &[])?;
+    println!("Sold {mint} to {bidder} for {price} lamports ({signature})");
+    Ok(())
+}
+
+/// When an auction runs, relative to now, and whether it is a Dutch auction.
+pub struct AuctionSchedule {
+    pub start_in: i64,
+    pub duration: i64,
+    pub dutch_start_price: Option<u64>,
+}
+
+pub fn create_auction(
+    ctx: &Context,
+    mint: &Pubkey,
+    reserve_price: u64,
+    min_increment: u64,
+    schedule: AuctionSchedule,
+) -> Result<()> {
+    let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH)?.as_secs() as i64;
+    let start_time = now + schedule.start_in;
+    let params = client::AuctionParams {
+        kind: match schedule.dutch_start_price {
+            Some(start_price) => client::AuctionKind::Dutch { start_price },
+            None => client::AuctionKind::English,
+        },
+        reserve_price,
+        min_increment,
+        start_time,
+        end_time: start_time + schedule.duration,
+    };
+    let ix = client::create_auction(ctx.payer.pubkey(), *mint, params);
+    let signature = ctx.send(&[ix], &[])?;
+
+    println!("Auction: {}", client::find_auction(mint).0);
+    println!("Signature: {signature}");
+    Ok(())
+}
+
+fn fetch_auction(ctx: &Context, mint: &Pubkey) -> Result<client::Auction> {
+    let data = ctx
+        .rpc
+        .get_account_data(&client::find_auction(mint).0)?
+        .ok_or_else(|| anyhow!("{mint} is not being auctioned"))?;
+    Ok(client::decode_auction(&data)?)
+}
+
+pub fn auction_bid(ctx: &Context, mint: &Pubkey, amount: u64) -> Result<()> {
+    let auction = fetch_auction(ctx, mint)?;
+    let ix = client::place_auction_bid(ctx.payer.pubkey(), *mint, auction.highest_bidder, amount);
+    let signature = ctx.send(&[ix], &[])?;
+    println!("Bid on {mint} ({signature})");
+    Ok(())
+}
+
+pub fn settle_auctions(ctx: &Context, mints: &[Pubkey]) -> Result<()> {
+    for mint in mints {
+        let auction = fetch_auction(ctx, mint)?;
+        let creators = ctx.creators_of(mint)?;
+        let lite = ctx.rpc.get_account_data(&client::find_nft_info(mint).0)?.is_none();
+        let ix = client::settle_auction(
+            ctx.payer.pubkey(),
+            auction.seller,
+            auction.highest_bidder,
+            *mint,
+            ctx.token_program_of(mint)?,
+            &creators,
+            lite,
+        );
+        let signature = ctx.send(&[ix], &[])?;
+        match auction.highest_bidder {
+            Some(winner) => println!("Settled {mint}: sold to {winner} for {} lamports ({signature})", auction.highest_bid),
+            None => println!("Settled {mint}: no bids ({signature})"),
+        }
+    }
+    Ok(())
+}
+
+/// Parses a 32-byte hash written as 64 hex characters.
+pub fn parse_hash(hex: &str) -> Result<[u8; 32]> {
+    let hex = hex.trim_start_matches("0x");
+    if hex.len() != 64 || !hex.is_ascii() {
+        return Err(anyhow!("expected 64 hex characters"));
+    }
+    let mut hash = [0u8; 32];
+    for (byte, chunk) in hash.iter_mut().zip(hex.as_bytes().chunks(2)) {
+        *byte = u8::from_str_radix(std::str::from_utf8(chunk)?, 16)?;
+    }
+    Ok(hash)
+}
+
+pub fn configure_reveal(ctx: &Context, collection: &Pubkey, placeholder_uri: String, provenance_hash: [u8; 32]) -> Result<()> {
+    let ix = client::configure_reveal(ctx.payer.pubkey(), *collection, placeholder_uri, provenance_hash);
+    let signature = ctx.send(&[ix], &[])?;
+    println!("Reveal configured ({signature})");
+    Ok(())
+}
+
+/// NFTs revealed per transaction, each one adds two accounts and a Token Metadata update.
+const REVEAL_BATCH_SIZE: usize = 5;
+
+pub fn reveal(ctx: &Context, collection: &Pubkey, base_uri: &str, mints: &[Pubkey]) -> Result<()> {
+    for batch in mints.chunks(REVEAL_BATCH_SIZE) {
+        let ix = client::reveal(ctx.payer.pubkey(), *collection, base_uri.to_string(), batch);
+        let signature = ctx.send(&[ix], &[])?;
+        println!("Revealed {} NFTs ({signature})", batch.len());
+    }
+    Ok(())
+}
+
+/// Pool items uploaded per transaction, each one takes up to 240 bytes of instruction data.
+const POOL_CHUNK_SIZE: usize = 3;
+
+/// Creates the item pool of `collection` from a mint manifest, or resumes the upload of an
+/// existing one after its last loaded item. Every item must share royalties and creators,
+/// items leaving them out take the collection defaults.
+pub fn load_pool(ctx: &Context, collection: &Pubkey, manifest_path: &Path) -> Result<()> {
+    let manifest: Manifest = spec::load(manifest_path)?;
+    let nfts = manifest
+        .nfts
+        .iter()
+        .map(|item| item.to_nft_data())
+        .collect::<Result<Vec<_>>>()?;
+    let first = nfts.first().ok_or_else(|| anyhow!("manifest has no items"))?;
+    let collection_info = ctx.collection_info(collection)?;
+    let royalties = |nft: &client::NftData| {
+        let creators = nft.creators.clone().unwrap_or_else(|| collection_info.creators.clone());
+        (nft.seller_fee_basis_points.unwrap_or(collection_info.seller_fee_basis_points), creators)
+    };
+    let (seller_fee_basis_points, creators) = royalties(first);
+    let same_royalties = |nft: &client::NftData| {
+        let (fee, other) = royalties(nft);
+        fee == seller_fee_basis_points
+            && other.len() == creators.len()
+            && other.iter().zip(&creators).all(|(a, b)| a.address == b.address && a.share == b.share)
+    };
+    if !nfts.iter().all(same_royalties) {
+        return Err(anyhow!("every pool item must have the same royalties and creators"));
+    }
+
+    let pool = client::find_item_pool(collection).0;
+    let loaded = match ctx.rpc.get_account_data(&pool)? {
+        Some(data) => client::decode_item_pool(&data)?.loaded as usize,
+        None => {
+            let capacity = u32::try_from(nfts.len())?;
+            let ix = client::create_item_pool(
+                ctx.payer.pubkey(),
+                *collection,
+                capacity,
+                seller_fee_basis_points,
+                creators.clone(),
+            );
+            let signature = ctx.send(&[ix], &[])?;
+            println!("Item pool {pool} created for {capacity} items ({signature})");
+            0
+        }
+    };
+
+    for (offset, chunk) in nfts[loaded..].chunks(POOL_CHUNK_SIZE).enumerate() {
+        let items = chunk
+            .iter()
+            .map(|nft| PoolItem { name: nft.name.clone(), uri: nft.uri.clone() })
+            .collect();
+        let ix = client::add_pool_items(ctx.payer.pubkey(), *collection, items);
+        let signature = ctx.send(&[ix], &[])?;
+        println!("Loaded {} items ({signature})", loaded + offset * POOL_CHUNK_SIZE + chunk.len());
+    }
+    Ok(())
+}
+
+pub fn mint_from_pool(ctx: &Context, collection: &Pubkey, count: u32) -> Result<()> {
+    let build = if ctx.collection_info(collection)?.soulbound {
+        client::mint_from_pool_soulbound
+    } else {
+        client::mint_from_pool
+    };
+    for _ in 0..count {
+        let mint = Keypair::new();
+        let ix = build(ctx.payer.pubkey(), mint.pubkey(), *collection);
+        let signature = ctx.send(&[ix], &[&mint])?;
+        println!("Minted {} ({signature})", mint.pubkey());
+    }
+    Ok(())
+}
+
+pub fn set_template(ctx: &Context, collection: &Pubkey, spec_path: &Path) -> Result<()> {
+    let spec: TemplateSpec = spec::load(spec_path)?;
+    let ix = client::set_mint_template(ctx.payer.pubkey(), *collection, spec.to_template_data()?);
+    let signature = ctx.send(&[ix], &[])?;
+    println!("Mint template set ({signature})");
+    Ok(())
+}
+
+pub fn mint_next(ctx: &Context, collection: &Pubkey, count: u32, lite: bool) -> Result<()> {
+    // Soulbound items always get their `NftInfo`, like `mint --soulbound`.
+    let build = if ctx.collection_info(collection)?.soulbound {
+        client::mint_next_soulbound
+    } else if lite {
+        client::mint_next_lite
+    } else {
+        client::mint_next
+    };
+    for _ in 0..count {
+        let mint = Keypair::new();
+        let ix = build(ctx.payer.pubkey(), mint.pubkey(), *collection);
+        let signature = ctx.send(&[ix], &[&mint])?;
+        println!("Minted {} ({signature})", mint.pubkey());
+    }
+    Ok(())
+}
+
+pub fn create_staking_pool(ctx: &Context, collection: &Pubkey, reward_mint: &Pubkey, reward_rate: u64) -> Result<()> {
+    let ix = client::create_staking_pool(ctx.payer.pubkey(), *collection, *reward_mint, reward_rate);
+    let signature = ctx.send(&[ix], &[])?;
+
+    let pool = client::find_staking_pool(collection).0;
+    let vault = client::find_token_account(&pool, reward_mint, &ctx.token_program_of(reward_mint)?);
+    println!("Staking pool: {pool}");
+    println!("Reward vault, funded by the creator unless the pool is the mint authority: {vault}");
+    println!("Signature: {signature}");
+    Ok(())
+}
+
+pub fn stake(ctx: &Context, collection: &Pubkey, mints: &[Pubkey], token_2022: bool) -> Result<()> {
+    let (token_program, token_metadata) = nft_token_program(token_2022);
+    for mint in mints {
+        let ix = client::stake_nft(ctx.payer.pubkey(), *mint, *collection, token_program, token_metadata);
+        let signature = ctx.send(&[ix], &[])?;
+        println!("Staked {mint} ({signature})");
+    }
+    Ok(())
+}
+
+pub fn unstake(ctx: &Context, collection: &Pubkey, mints: &[Pubkey], token_2022: bool) -> Result<()> {
+    let (token_program, token_metadata) = nft_token_program(token_2022);
+    let rewards = ctx.reward_options(collection)?;
+    for mint in mints {
+        let ix = client::unstake_nft(ctx.payer.pubkey(), *mint, *collection, token_program, token_metadata, rewards);
+        let signature = ctx.send(&[ix], &[])?;
+        println!("Unstaked {mint} ({signature})");
+    }
+    Ok(())
+}
+
+pub fn claim_rewards(ctx: &Context, collection: &Pubkey, mints: &[Pubkey]) -> Result<()> {
+    let rewards = ctx.reward_options(collection)?;
+    for mint in mints {
+        let ix = client::claim_rewards(ctx.payer.pubkey(), *mint, *collection, rewards);
+        let signature = ctx.send(&[ix], &[])?;
+        println!("Claimed rewards of {mint} ({signature})");
+    }
+    Ok(())
+}
+
+pub fn show_collection(rpc: &RpcClient, mint: &Pubkey) -> Result<()> {
+    let address = client::find_collection_info(mint).0;
+    let data = rpc
+        .get_account_data(&address)?
+        .ok_or_else(|| anyhow!("no CollectionInfo account at {address}"))?;
+    let info = client::decode_collection_info(&data)?;
+    println!("{}", serde_json::to_string_pretty(&collection_info_json(&info))?);
+    Ok(())
+}
+
+/// Prints the chain of parent collections of `mint`, from the collection itself to the top.
+pub fn show_ancestry(rpc: &RpcClient, mint: &Pubkey) -> Result<()> {
+    let ancestry = client::collection_ancestry(mint, |address| rpc.get_account_data(address))?;
+    if ancestry.is_empty() {
+        return Err(anyhow!("no CollectionInfo account for {mint}"));
+    }
+    for (depth, info) in ancestry.iter().enumerate() {
+        println!("{}{} ({})", "  ".repeat(depth), info.name, info.mint);
+    }
+    Ok(())
+}
+
+pub fn show_nft(rpc: &RpcClient, mint: &Pubkey) -> Result<()> {
+    let address = client::find_nft_info(mint).0;
+    let data = rpc
+        .get_account_data(&address)?
+        .ok_or_else(|| anyhow!("no NftInfo account at {address}"))?;
+    let info = client::decode_nft_info(&data)?;
+    println!("{}", serde_json::to_string_pretty(&nft_info_json(&info))?);
+    Ok(())
+}
+
+pub fn migrate_collection(ctx: &Context, mint: &Pubkey, next_index: u64) -> Result<()> {
+    let ix = client::migrate_collection_info(ctx.payer.pubkey(), *mint, next_index);
+    let signature = ctx.send(&[ix], &[])?;
+    println!("Migrated CollectionInfo of {mint} ({signature})");
+    Ok(())
+}
+
+pub fn migrate_nfts(ctx: &Context, mints: &[Pubkey], collection: Option<Pubkey>) -> Result<()> {
+    for mint in mints {
+        let ix = client::migrate_nft_info(ctx.payer.pubkey(), *mint, collection);
+        let signature = ctx.send(&[ix], &[])?;
+        println!("Migrated NftInfo of {mint} ({signature})");
+    }
+    Ok(())
+}
@@ -0,0 +1,493 @@
+use std::{
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use anyhow::{anyhow, bail, Context as _, Result};
+use serde::{Deserialize, Serialize};
+use solana_sdk::{bs58, pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+use nft_program_client::{
+    self as client,
+    anchor_lang::error::Error as ProgramError,
+    validation::ValidatableData,
+    NftData,
+};
+
+use crate::{
+    commands::Context,
+    spec::{CreatorSpec, NftSpec},
+};
+
+/// A CSV row. `creators` is a `;` separated list of `ADDRESS:SHARE` pairs, royalty columns
+/// left empty are inherited from the collection.
+#[derive(Deserialize, Debug)]
+struct CsvRow {
+    name: String,
+    symbol: String,
+    uri: String,
+    seller_fee_basis_points: Option<u16>,
+    #[serde(default)]
+    creators: String,
+}
+
+impl TryFrom<CsvRow> for NftSpec {
+    type Error = anyhow::Error;
+
+    fn try_from(row: CsvRow) -> Result<Self> {
+        let creators = row
+            .creators
+            .split(';')
+            .filter(|entry| !entry.trim().is_empty())
+            .map(|entry| {
+                let (address, share) = entry
+                    .trim()
+                    .split_once(':')
+                    .ok_or_else(|| anyhow!("creator `{entry}` is not ADDRESS:SHARE"))?;
+                Ok(CreatorSpec {
+                    address: address.to_string(),
+                    verified: false,
+                    share: share.parse().with_context(|| format!("invalid share in `{entry}`"))?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let creators = (!creators.is_empty()).then_some(creators);
+
+        Ok(NftSpec {
+            name: row.name,
+            symbol: row.symbol,
+            uri: row.uri,
+            seller_fee_basis_points: row.seller_fee_basis_points,
+            creators,
+            uses: None,
+        })
+    }
+}
+
+/// Reads a drop manifest, either CSV (`.csv`) or JSON lines (anything else).
+pub fn load_manifest(path: &Path) -> Result<Vec<NftSpec>> {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("csv") => {
+            let mut reader = csv::Reader::from_path(path)
+                .with_context(|| format!("failed to read {}", path.display()))?;
+            reader
+                .deserialize::<CsvRow>()
+                .enumerate()
+                .map(|(index, row)| {
+                    row.map_err(anyhow::Error::from)
+                        .and_then(NftSpec::try_from)
+                        .with_context(|| format!("row {}", index + 1))
+                })
+                .collect()
+        }
+        _ => {
+            let contents = fs::read_to_string(path)
+                .with_context(|| format!("failed to read {}", path.display()))?;
+            contents
+                .lines()
+                .enumerate()
+                .filter(|(_, line)| !line.trim().is_empty())
+                .map(|(index, line)| {
+                    serde_json::from_str(line).with_context(|| format!("line {}", index + 1))
+                })
+                .collect()
+        }
+    }
+}
+
+fn rule_violation(err: ProgramError) -> anyhow::Error {
+    match err {
+        ProgramError::AnchorError(err) => anyhow!("{}", err.error_msg),
+        ProgramError::ProgramError(err) => anyhow!("{}", err.program_error),
+    }
+}
+
+/// Checks every item against the on-chain rules and reports all failures at once.
+pub fn validate_manifest(items: &[NftSpec]) -> Result<Vec<NftData>> {
+    let mut errors = Vec::new();
+    let mut data = Vec::with_capacity(items.len());
+
+    for (index, item) in items.iter().enumerate() {
+        match item.to_nft_data().and_then(|nft_data| {
+            nft_data.validate().map_err(rule_violation)?;
+            Ok(nft_data)
+        }) {
+            Ok(nft_data) => data.push(nft_data),
+            Err(err) => errors.push(format!("item {index} ({}): {err:#}", item.name)),
+        }
+    }
+
+    if !errors.is_empty() {
+        bail!("manifest has {} invalid item(s):\n{}", errors.len(), errors.join("\n"));
+    }
+    Ok(data)
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ItemStatus {
+    /// Mint keypair generated and persisted, transaction possibly sent.
+    Pending,
+    Minted,
+    Verified,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CacheEntry {
+    pub name: String,
+    pub uri: String,
+    pub mint: String,
+    /// Base58 mint keypair, kept so a pending mint is retried with the same address.
+    pub mint_keypair: String,
+    pub status: ItemStatus,
+}
+
+impl CacheEntry {
+    /// Decodes `mint_keypair`, failing on a corrupt cache instead of panicking.
+    pub fn mint_keypair(&self) -> Result<Keypair> {
+        let bytes = bs58::decode(&self.mint_keypair)
+            .into_vec()
+            .map_err(|err| anyhow!("cached mint keypair of {} is not base58: {err}", self.name))?;
+        let keypair = Keypair::try_from(bytes.as_slice())
+            .map_err(|err| anyhow!("cached mint keypair of {} is invalid: {err}", self.name))?;
+        if keypair.pubkey().to_string() != self.mint {
+            bail!("cached mint keypair of {} does not match mint {}", self.name, self.mint);
+        }
+        Ok(keypair)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct DropCache {
+    pub collection: String,
+    pub items: Vec<CacheEntry>,
+}
+
+impl DropCache {
+    pub fn load_or_default(path: &Path, collection: &Pubkey) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self {
+                collection: collection.to_string(),
+                items: Vec::new(),
+            });
+        }
+
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("failed to read cache {}", path.display()))?;
+        let cache: Self = serde_json::from_str(&contents)
+            .with_context(|| format!("failed to parse cache {}", path.display()))?;
+        if cache.collection != collection.to_string() {
+            bail!(
+                "cache {} belongs to collection {}, not {collection}",
+                path.display(),
+                cache.collection
+            );
+        }
+        Ok(cache)
+    }
+
+    /// Fails unless every cached entry is the manifest item at the same position, so a resumed
+    /// drop never mints an item under another item's cached mint.
+    pub fn check_manifest(&self, items: &[NftSpec]) -> Result<()> {
+        for (index, entry) in self.items.iter().enumerate() {
+            let item = items
+                .get(index)
+                .ok_or_else(|| anyhow!("cache has {} items but manifest only {}", self.items.len(), items.len()))?;
+            if entry.name != item.name || entry.uri != item.uri {
+                bail!("manifest item {index} ({}) does not match cached entry ({})", item.name, entry.name);
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds a `Pending` entry with a fresh mint keypair for item `index` if it has none yet,
+    /// returning whether one was added. Existing entries keep their mint so a retry reuses it.
+    pub fn push_pending(&mut self, index: usize, data: &NftData) -> bool {
+        if index < self.items.len() {
+            return false;
+        }
+        let mint = Keypair::new();
+        self.items.push(CacheEntry {
+            name: data.name.clone(),
+            uri: data.uri.clone(),
+            mint: mint.pubkey().to_string(),
+            mint_keypair: mint.to_base58_string(),
+            status: ItemStatus::Pending,
+        });
+        true
+    }
+
+    /// Writes through a temporary file so an interrupted save never truncates the cache. The
+    /// cache holds mint keypairs, so on Unix the file is only readable by its owner.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let tmp = path.with_extension("tmp");
+        // A leftover temporary file keeps its permissions, so start from a fresh one.
+        if tmp.exists() {
+            fs::remove_file(&tmp)?;
+        }
+
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        let mut file = options
+            .open(&tmp)
+            .with_context(|| format!("failed to create {}", tmp.display()))?;
+        file.write_all(&serde_json::to_vec_pretty(self)?)?;
+        file.sync_all()?;
+
+        fs::rename(&tmp, path)?;
+        Ok(())
+    }
+}
+
+pub fn default_cache_path(manifest: &Path) -> PathBuf {
+    let mut path = manifest.as_os_str().to_owned();
+    path.push(".cache.json");
+    PathBuf::from(path)
+}
+
+pub fn validate(manifest_path: &Path) -> Result<()> {
+    let items = load_manifest(manifest_path)?;
+    validate_manifest(&items)?;
+    println!("{} item(s) valid", items.len());
+    Ok(())
+}
+
+pub fn run(
+    ctx: &Context,
+    collection: &Pubkey,
+    manifest_path: &Path,
+    cache_path: Option<&Path>,
+    verify: bool,
+    lite: bool,
+) -> Result<()> {
+    let items = load_manifest(manifest_path)?;
+    let nft_data = validate_manifest(&items)?;
+
+    let cache_path = cache_path
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| default_cache_path(manifest_path));
+    let mut cache = DropCache::load_or_default(&cache_path, collection)?;
+    let soulbound = ctx.collection_info(collection)?.soulbound;
+
+    cache.check_manifest(&items)?;
+
+    for (index, data) in nft_data.into_iter().enumerate() {
+        if cache.push_pending(index, &data) {
+            cache.save(&cache_path)?;
+        }
+
+        let entry = &mut cache.items[index];
+        let mint = entry.mint_keypair()?;
+        let mint_address = Pubkey::from_str(&entry.mint)?;
+
+        if entry.status == ItemStatus::Pending {
+            // The mint account only exists once the whole mint transaction landed.
+            let already_minted = ctx.rpc.get_account_data(&mint_address)?.is_some();
+
+            if !already_minted {
+                let build = if soulbound {
+                    client::mint_soulbound_nft
+                } else if lite {
+                    client::mint_nft_lite
+                } else {
+                    client::mint_nft
+                };
+                let ix = build(ctx.payer.pubkey(), mint_address, *collection, data);
+                let signature = ctx
+                    .send(&[ix], &[&mint])
+                    .with_context(|| format!("failed to mint item {index} ({})", entry.name))?;
+                println!("[{index}] minted {} as {mint_address} ({signature})", entry.name);
+            } else {
+                println!("[{index}] {} already on chain as {mint_address}", entry.name);
+            }
+
+            entry.status = ItemStatus::Minted;
+            cache.save(&cache_path)?;
+        }
+
+        let entry = &mut cache.items[index];
+        if verify && entry.status == ItemStatus::Minted {
+            let ix = ctx.verify_instruction(&mint_address, collection)?;
+            let signature = ctx
+                .send(&[ix], &[])
+                .with_context(|| format!("failed to verify item {index} ({})", entry.name))?;
+            println!("[{index}] verified {mint_address} ({signature})");
+
+            entry.status = ItemStatus::Verified;
+            cache.save(&cache_path)?;
+        }
+    }
+
+    println!("Drop complete, cache at {}", cache_path.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CREATOR: &str = "11111111111111111111111111111111";
+
+    /// A file under the system temp directory, unique to this test run.
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("nft-cli-{}-{name}", std::process::id()))
+    }
+
+    fn write_manifest(name: &str, contents: &str) -> PathBuf {
+        let path = temp_path(name);
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    fn item(name: &str, uri: &str) -> NftSpec {
+        NftSpec {
+            name: name.to_string(),
+            symbol: "TNFT".to_string(),
+            uri: uri.to_string(),
+            seller_fee_basis_points: None,
+            creators: None,
+            uses: None,
+        }
+    }
+
+    #[test]
+    fn csv_rows_parse_creators() {
+        let path = write_manifest(
+            "creators.csv",
+            &format!(
+                "name,symbol,uri,seller_fee_basis_points,creators\n\
+                 A,TNFT,https://example.com/a.json,250,{CREATOR}:60; {CREATOR}:40\n\
+                 B,TNFT,https://example.com/b.json,,\n"
+            ),
+        );
+        let items = load_manifest(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        let creators = items[0].creators.as_ref().unwrap();
+        assert_eq!(creators.len(), 2);
+        assert_eq!(creators[0].address, CREATOR);
+        assert_eq!(creators[0].share, 60);
+        assert_eq!(creators[1].share, 40);
+        assert_eq!(items[0].seller_fee_basis_points, Some(250));
+        assert!(items[1].creators.is_none());
+        assert!(items[1].seller_fee_basis_points.is_none());
+    }
+
+    #[test]
+    fn csv_creator_without_share_is_rejected() {
+        let path = write_manifest(
+            "bad-creator.csv",
+            &format!("name,symbol,uri,seller_fee_basis_points,creators\nA,TNFT,https://example.com/a.json,250,{CREATOR}\n"),
+        );
+        let err = load_manifest(&path).unwrap_err();
+        fs::remove_file(&path).unwrap();
+
+        let message = format!("{err:#}");
+        assert!(message.contains("row 1"), "{message}");
+        assert!(message.contains("is not ADDRESS:SHARE"), "{message}");
+    }
+
+    #[test]
+    fn json_lines_report_the_bad_line() {
+        let path = write_manifest(
+            "bad-line.jsonl",
+            "{\"name\": \"A\", \"symbol\": \"TNFT\", \"uri\": \"https://example.com/a.json\"}\n\n{\"name\": \"B\"}\n",
+        );
+        let err = load_manifest(&path).unwrap_err();
+        fs::remove_file(&path).unwrap();
+
+        assert!(format!("{err:#}").contains("line 3"), "{err:#}");
+    }
+
+    #[test]
+    fn validate_manifest_reports_every_invalid_item() {
+        let items = vec![
+            item("A", "https://example.com/a.json"),
+            item(&"B".repeat(64), "https://example.com/b.json"),
+            item("C", ""),
+        ];
+        let err = validate_manifest(&items).unwrap_err().to_string();
+
+        assert!(err.contains("manifest has 2 invalid item(s)"), "{err}");
+        assert!(err.contains("item 1"), "{err}");
+        assert!(err.contains("item 2 (C)"), "{err}");
+        assert!(!err.contains("item 0"), "{err}");
+    }
+
+    #[test]
+    fn cache_of_another_collection_is_rejected() {
+        let path = temp_path("other-collection.cache.json");
+        let cache = DropCache {
+            collection: Pubkey::new_unique().to_string(),
+            items: Vec::new(),
+        };
+        cache.save(&path).unwrap();
+
+        let err = DropCache::load_or_default(&path, &Pubkey::new_unique()).unwrap_err();
+        fs::remove_file(&path).unwrap();
+
+        assert!(err.to_string().contains("belongs to collection"), "{err}");
+    }
+
+    #[test]
+    fn cache_not_matching_the_manifest_is_rejected() {
+        let mut cache = DropCache::default();
+        let data = item("A", "https://example.com/a.json").to_nft_data().unwrap();
+        cache.push_pending(0, &data);
+
+        let renamed = [item("B", "https://example.com/a.json")];
+        let err = cache.check_manifest(&renamed).unwrap_err();
+        assert!(err.to_string().contains("does not match cached entry"), "{err}");
+
+        let err = cache.check_manifest(&[]).unwrap_err();
+        assert!(err.to_string().contains("cache has 1 items but manifest only 0"), "{err}");
+
+        cache.check_manifest(&[item("A", "https://example.com/a.json")]).unwrap();
+    }
+
+    #[test]
+    fn pending_entry_is_resumed_with_its_mint() {
+        let path = temp_path("resume.cache.json");
+        let collection = Pubkey::new_unique();
+        let items = [item("A", "https://example.com/a.json"), item("B", "https://example.com/b.json")];
+
+        let mut cache = DropCache::load_or_default(&path, &collection).unwrap();
+        assert!(cache.push_pending(0, &items[0].to_nft_data().unwrap()));
+        cache.save(&path).unwrap();
+        let mint = cache.items[0].mint.clone();
+
+        // An interrupted drop leaves the entry pending, the next run picks it up as is.
+        let mut resumed = DropCache::load_or_default(&path, &collection).unwrap();
+        fs::remove_file(&path).unwrap();
+        resumed.check_manifest(&items).unwrap();
+        assert!(!resumed.push_pending(0, &items[0].to_nft_data().unwrap()));
+        assert_eq!(resumed.items[0].status, ItemStatus::Pending);
+        assert_eq!(resumed.items[0].mint, mint);
+        assert_eq!(resumed.items[0].mint_keypair().unwrap().pubkey().to_string(), mint);
+
+        assert!(resumed.push_pending(1, &items[1].to_nft_data().unwrap()));
+        assert_ne!(resumed.items[1].mint, mint);
+    }
+
+    #[test]
+    fn corrupt_mint_keypair_is_rejected() {
+        let mut cache = DropCache::default();
+        cache.push_pending(0, &item("A", "https://example.com/a.json").to_nft_data().unwrap());
+        cache.items[0].mint = Pubkey::new_unique().to_string();
+
+        let err = cache.items[0].mint_keypair().unwrap_err();
+        assert!(err.to_string().contains("does not match mint"), "{err}");
+
+        cache.items[0].mint_keypair = "not base58!".to_string();
+        assert!(cache.items[0].mint_keypair().is_err());
+    }
+
+    #[test]
+    fn default_cache_path_extends_the_manifest_path() {
+        assert_eq!(default_cache_path(Path::new("drop/items.csv")), PathBuf::from("drop/items.csv.cache.json"));
+    }
+}
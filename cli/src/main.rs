@@ -0,0 +1,482 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::rpc::RpcClient;
+
+mod commands;
+mod drop;
+mod rpc;
+mod spec;
+
+#[derive(Parser)]
+#[command(name = "nft-cli", about = "Operate NFT program collections")]
+struct Cli {
+    /// JSON RPC endpoint of the cluster to target
+    #[arg(long, short = 'u', global = true, default_value = "http://127.0.0.1:8899")]
+    url: String,
+
+    /// Fee payer and authority keypair
+    #[arg(long, short = 'k', global = true, default_value = "~/.config/solana/id.json")]
+    keypair: String,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create a collection from a JSON or TOML spec
+    CreateCollection {
+        spec: PathBuf,
+        /// Keypair to use for the collection mint, a fresh one is generated otherwise
+        #[arg(long)]
+        mint_keypair: Option<String>,
+        /// Create a Token-2022 group collection holding at most this many items
+        #[arg(long, value_name = "MAX_SIZE")]
+        token_2022_group: Option<u64>,
+        /// Create a Metaplex Core collection
+        #[arg(long, conflicts_with = "token_2022_group")]
+        core: bool,
+        /// Commit to the hex SHA-256 of the final metadata and draw a random starting offset
+        #[arg(long, value_parser = commands::parse_hash, conflicts_with_all = ["token_2022_group", "core"])]
+        provenance_hash: Option<[u8; 32]>,
+        /// Verify the new collection into this parent collection, created by the same payer
+        #[arg(long, conflicts_with_all = ["token_2022_group", "core"])]
+        parent: Option<Pubkey>,
+    },
+    /// Mint every NFT listed in a JSON or TOML manifest into a collection
+    Mint {
+        #[arg(long)]
+        collection: Pubkey,
+        manifest: PathBuf,
+        /// Verify each NFT into the collection right after minting
+        #[arg(long)]
+        verify: bool,
+        /// Skip the NftInfo account and rely on Token Metadata alone
+        #[arg(long)]
+        lite: bool,
+        /// Mint Token-2022 NFTs carrying their own metadata into a Token-2022 group collection
+        #[arg(long, conflicts_with = "verify")]
+        token_2022: bool,
+        /// Mint programmable NFTs whose transfers go through Token Metadata
+        #[arg(long, conflicts_with = "token_2022")]
+        programmable: bool,
+        /// Rule set to bind the programmable NFTs to
+        #[arg(long, requires = "programmable")]
+        rule_set: Option<Pubkey>,
+        /// Mint Metaplex Core assets into a Core collection
+        #[arg(long, conflicts_with_all = ["verify", "token_2022", "programmable"])]
+        core: bool,
+        /// Mint soulbound NFTs, frozen in the payer's wallet until the collection creator revokes them
+        #[arg(long, conflicts_with_all = ["lite", "token_2022", "programmable", "core"])]
+        soulbound: bool,
+    },
+    /// Allocate a Bubblegum Merkle tree for compressed NFTs of a collection
+    CreateTree {
+        #[arg(long)]
+        collection: Pubkey,
+        /// Tree depth, the tree holds 2^depth compressed NFTs
+        #[arg(long, default_value_t = 14)]
+        max_depth: u32,
+        /// Number of concurrent changes the tree accepts per slot
+        #[arg(long, default_value_t = 64)]
+        max_buffer_size: u32,
+        /// Tree levels cached on chain to shorten proofs
+        #[arg(long, default_value_t = 0)]
+        canopy_depth: u32,
+    },
+    /// Mint every NFT listed in a JSON or TOML manifest as a compressed NFT into the collection's tree
+    MintCompressed {
+        #[arg(long)]
+        collection: Pubkey,
+        manifest: PathBuf,
+        /// Owner of the compressed NFTs, defaults to the fee payer
+        #[arg(long)]
+        owner: Option<Pubkey>,
+    },
+    /// Verify minted NFTs into their collection
+    Verify {
+        #[arg(long)]
+        collection: Pubkey,
+        #[arg(required = true)]
+        mints: Vec<Pubkey>,
+    },
+    /// Remove verified NFTs from their collection, shrinking its size
+    Unverify {
+        #[arg(long)]
+        collection: Pubkey,
+        #[arg(required = true)]
+        mints: Vec<Pubkey>,
+    },
+    /// Resync a collection's NFT count with the size Token Metadata records
+    ReconcileSize { collection: Pubkey },
+    /// Set the royalties a collection's items inherit, optionally enforced on every mint
+    SetRoyalties {
+        #[arg(long)]
+        collection: Pubkey,
+        spec: PathBuf,
+    },
+    /// Require every item of a collection to be minted soulbound; run before minting
+    SetSoulbound {
+        #[arg(long)]
+        collection: Pubkey,
+        /// Lift the requirement again
+        #[arg(long)]
+        disable: bool,
+    },
+    /// Consume one use of NFTs minted with uses, as their holder or approved use authority
+    UseNft {
+        #[arg(required = true)]
+        mints: Vec<Pubkey>,
+        /// Holder of the NFTs when the payer is their approved use authority
+        #[arg(long)]
+        owner: Option<Pubkey>,
+    },
+    /// Approve a wallet to use the payer's NFTs, or revoke the approval when no wallet is given
+    SetUseAuthority {
+        #[arg(required = true)]
+        mints: Vec<Pubkey>,
+        #[arg(long)]
+        authority: Option<Pubkey>,
+    },
+    /// Burn soulbound NFTs from their holder's wallet, as the collection creator
+    RevokeSoulbound {
+        #[arg(long)]
+        collection: Pubkey,
+        /// Holder of the NFTs
+        #[arg(long)]
+        owner: Pubkey,
+        #[arg(required = true)]
+        mints: Vec<Pubkey>,
+    },
+    /// Freeze NFTs in the payer's wallet for soft staking
+    Freeze {
+        #[arg(required = true)]
+        mints: Vec<Pubkey>,
+        /// The NFTs are Token-2022 NFTs without a Token Metadata master edition
+        #[arg(long)]
+        token_2022: bool,
+    },
+    /// Thaw NFTs frozen with `freeze`
+    Thaw {
+        #[arg(required = true)]
+        mints: Vec<Pubkey>,
+        /// The NFTs are Token-2022 NFTs without a Token Metadata master edition
+        #[arg(long)]
+        token_2022: bool,
+    },
+    /// Open a staking pool paying REWARD_RATE base units of the reward mint per NFT per second
+    CreateStakingPool {
+        #[arg(long)]
+        collection: Pubkey,
+        /// Minted by the pool when its PDA is the mint authority, paid from the pool's vault otherwise
+        #[arg(long)]
+        reward_mint: Pubkey,
+        #[arg(long)]
+        reward_rate: u64,
+    },
+    /// Stake verified NFTs of a collection into its staking pool
+    Stake {
+        #[arg(long)]
+        collection: Pubkey,
+        #[arg(required = true)]
+        mints: Vec<Pubkey>,
+        /// The NFTs are Token-2022 NFTs without a Token Metadata master edition
+        #[arg(long)]
+        token_2022: bool,
+    },
+    /// Unstake NFTs, paying out their pending rewards
+    Unstake {
+        #[arg(long)]
+        collection: Pubkey,
+        #[arg(required = true)]
+        mints: Vec<Pubkey>,
+        /// The NFTs are Token-2022 NFTs without a Token Metadata master edition
+        #[arg(long)]
+        token_2022: bool,
+    },
+    /// Claim the pending rewards of staked NFTs
+    ClaimRewards {
+        #[arg(long)]
+        collection: Pubkey,
+        #[arg(required = true)]
+        mints: Vec<Pubkey>,
+    },
+    /// Lend an NFT from the payer's wallet for DURATION seconds; the renter co-signs and pays FEE lamports
+    RentOut {
+        mint: Pubkey,
+        /// Keypair of the renter, who signs the rental
+        #[arg(long)]
+        renter_keypair: String,
+        #[arg(long)]
+        duration: i64,
+        #[arg(long, default_value_t = 0)]
+        fee: u64,
+        /// The NFT is a Token-2022 NFT without a Token Metadata master edition
+        #[arg(long)]
+        token_2022: bool,
+    },
+    /// Return expired rentals to their owners
+    Reclaim {
+        #[arg(required = true)]
+        mints: Vec<Pubkey>,
+        /// The NFTs are Token-2022 NFTs without a Token Metadata master edition
+        #[arg(long)]
+        token_2022: bool,
+    },
+    /// List a Token Metadata NFT from the payer's wallet for PRICE lamports
+    List {
+        mint: Pubkey,
+        #[arg(long)]
+        price: u64,
+    },
+    /// Cancel listings and release the NFTs
+    CancelListing {
+        #[arg(required = true)]
+        mints: Vec<Pubkey>,
+    },
+    /// Buy a listed NFT, paying royalties to its creators
+    Buy {
+        mint: Pubkey,
+        /// Price the listing is expected to have, the purchase fails if it differs
+        #[arg(long)]
+        price: u64,
+    },
+    /// Bid PRICE lamports on an NFT, or on any verified NFT of a collection with `--collection`
+    Bid {
+        /// NFT mint, or collection mint with `--collection`
+        target: Pubkey,
+        #[arg(long)]
+        price: u64,
+        #[arg(long)]
+        collection: bool,
+    },
+    /// Cancel the payer's bids and refund them
+    CancelBid {
+        /// NFT or collection mints the bids were placed on
+        #[arg(required = true)]
+        targets: Vec<Pubkey>,
+    },
+    /// Sell an NFT from the payer's wallet to a bid, paying royalties to its creators
+    AcceptBid {
+        mint: Pubkey,
+        #[arg(long)]
+        bidder: Pubkey,
+        /// Accept the bidder's collection-wide bid on this collection instead of a bid on the NFT
+        #[arg(long)]
+        collection: Option<Pubkey>,
+        /// Price the bid is expected to have, the sale fails if it differs
+        #[arg(long)]
+        price: u64,
+    },
+    /// Auction a Token Metadata NFT from the payer's wallet
+    CreateAuction {
+        mint: Pubkey,
+        /// Lowest winning price in lamports, the floor of a Dutch auction
+        #[arg(long)]
+        reserve_price: u64,
+        /// Minimum raise over the standing bid in lamports, English auctions only
+        #[arg(long, default_value_t = 0)]
+        min_increment: u64,
+        /// Seconds from now until bidding opens
+        #[arg(long, default_value_t = 0)]
+        start_in: i64,
+        /// Seconds bidding stays open
+        #[arg(long)]
+        duration: i64,
+        /// Run a Dutch auction decaying from this price to the reserve price
+        #[arg(long)]
+        dutch_start_price: Option<u64>,
+    },
+    /// Bid AMOUNT lamports in an auction; Dutch auctions charge the current price
+    AuctionBid {
+        mint: Pubkey,
+        #[arg(long)]
+        amount: u64,
+    },
+    /// Settle ended auctions, delivering the NFTs and paying sellers and creators
+    SettleAuction {
+        #[arg(required = true)]
+        mints: Vec<Pubkey>,
+    },
+    /// Give items a placeholder URI until `reveal`, committing to a provenance hash; run before minting
+    ConfigureReveal {
+        #[arg(long)]
+        collection: Pubkey,
+        #[arg(long)]
+        placeholder_uri: String,
+        /// SHA-256 of the final metadata as 64 hex characters
+        #[arg(long, value_parser = commands::parse_hash)]
+        provenance_hash: [u8; 32],
+    },
+    /// Point NFTs at BASE_URI followed by their index and `.json`
+    Reveal {
+        #[arg(long)]
+        collection: Pubkey,
+        #[arg(long)]
+        base_uri: String,
+        #[arg(required = true)]
+        mints: Vec<Pubkey>,
+    },
+    /// Create the item pool of a collection from a mint manifest, or resume its upload
+    LoadPool {
+        #[arg(long)]
+        collection: Pubkey,
+        manifest: PathBuf,
+    },
+    /// Mint NFTs drawn at random from the collection's item pool
+    MintFromPool {
+        #[arg(long)]
+        collection: Pubkey,
+        #[arg(long, default_value_t = 1)]
+        count: u32,
+    },
+    /// Set the name and URI template `mint-next` renders a collection's items from
+    SetTemplate {
+        #[arg(long)]
+        collection: Pubkey,
+        spec: PathBuf,
+    },
+    /// Mint the next numbered items of a collection from its template
+    MintNext {
+        #[arg(long)]
+        collection: Pubkey,
+        #[arg(long, default_value_t = 1)]
+        count: u32,
+        /// Skip the NftInfo account and rely on Token Metadata alone
+        #[arg(long)]
+        lite: bool,
+    },
+    /// Check a CSV or JSON lines drop manifest without sending anything
+    ValidateDrop { manifest: PathBuf },
+    /// Mint a CSV or JSON lines drop manifest in order, resuming from its cache
+    Drop {
+        #[arg(long)]
+        collection: Pubkey,
+        manifest: PathBuf,
+        /// Cache file tracking mint addresses and statuses, defaults to `<manifest>.cache.json`
+        #[arg(long)]
+        cache: Option<PathBuf>,
+        /// Verify each NFT into the collection after minting
+        #[arg(long)]
+        verify: bool,
+        /// Skip the NftInfo account and rely on Token Metadata alone
+        #[arg(long)]
+        lite: bool,
+    },
+    /// Rewrite a legacy CollectionInfo account into the current layout
+    MigrateCollection {
+        mint: Pubkey,
+        /// Index the next `mint` gets, e.g. to continue a numbered series
+        #[arg(long, default_value_t = 0)]
+        next_index: u64,
+    },
+    /// Rewrite legacy NftInfo accounts into the current layout, as their holder
+    MigrateNft {
+        #[arg(required = true)]
+        mints: Vec<Pubkey>,
+        /// Sign as the creator of this collection instead of the holder
+        #[arg(long)]
+        collection: Option<Pubkey>,
+    },
+    /// Print the CollectionInfo account of a collection mint as JSON
+    ShowCollection { mint: Pubkey },
+    /// Print a collection and the chain of parent collections above it
+    ShowAncestry { mint: Pubkey },
+    /// Print the NftInfo account of an NFT mint as JSON
+    ShowNft { mint: Pubkey },
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let context = || commands::Context::new(&cli.url, &cli.keypair);
+
+    match cli.command {
+        Command::CreateCollection { spec, mint_keypair, token_2022_group, core, provenance_hash, parent } => {
+            let kind = match token_2022_group {
+                Some(max_size) => commands::CollectionKind::Token2022Group { max_size },
+                None if core => commands::CollectionKind::Core,
+                None => commands::CollectionKind::Metaplex { provenance_hash, parent },
+            };
+            commands::create_collection(&context()?, &spec, mint_keypair.as_deref(), kind)
+        }
+        Command::Mint { collection, manifest, verify, lite, token_2022, programmable, rule_set, core, soulbound } => {
+            let standard = if token_2022 {
+                commands::MintStandard::Token2022
+            } else if core {
+                commands::MintStandard::Core
+            } else if programmable {
+                commands::MintStandard::Programmable { rule_set }
+            } else if soulbound {
+                commands::MintStandard::Soulbound
+            } else {
+                commands::MintStandard::Metaplex
+            };
+            commands::mint(&context()?, &collection, &manifest, verify, lite, standard)
+        }
+        Command::CreateTree { collection, max_depth, max_buffer_size, canopy_depth } => {
+            commands::create_tree(&context()?, &collection, max_depth, max_buffer_size, canopy_depth)
+        }
+        Command::MintCompressed { collection, manifest, owner } => {
+            commands::mint_compressed(&context()?, &collection, &manifest, owner)
+        }
+        Command::Verify { collection, mints } => commands::verify(&context()?, &collection, &mints),
+        Command::Unverify { collection, mints } => commands::unverify(&context()?, &collection, &mints),
+        Command::ReconcileSize { collection } => commands::reconcile_size(&context()?, &collection),
+        Command::SetRoyalties { collection, spec } => commands::set_royalties(&context()?, &collection, &spec),
+        Command::SetSoulbound { collection, disable } => commands::set_soulbound(&context()?, &collection, !disable),
+        Command::UseNft { mints, owner } => commands::use_nft(&context()?, &mints, owner),
+        Command::SetUseAuthority { mints, authority } => commands::set_use_authority(&context()?, &mints, authority),
+        Command::RevokeSoulbound { collection, owner, mints } => {
+            commands::revoke_soulbound(&context()?, &collection, &owner, &mints)
+        }
+        Command::Freeze { mints, token_2022 } => commands::set_frozen(&context()?, &mints, token_2022, true),
+        Command::Thaw { mints, token_2022 } => commands::set_frozen(&context()?, &mints, token_2022, false),
+        Command::CreateStakingPool { collection, reward_mint, reward_rate } => {
+            commands::create_staking_pool(&context()?, &collection, &reward_mint, reward_rate)
+        }
+        Command::Stake { collection, mints, token_2022 } => commands::stake(&context()?, &collection, &mints, token_2022),
+        Command::Unstake { collection, mints, token_2022 } => {
+            commands::unstake(&context()?, &collection, &mints, token_2022)
+        }
+        Command::ClaimRewards { collection, mints } => commands::claim_rewards(&context()?, &collection, &mints),
+        Command::RentOut { mint, renter_keypair, duration, fee, token_2022 } => {
+            commands::rent_out(&context()?, &mint, &renter_keypair, duration, fee, token_2022)
+        }
+        Command::Reclaim { mints, token_2022 } => commands::reclaim(&context()?, &mints, token_2022),
+        Command::List { mint, price } => commands::list(&context()?, &mint, price),
+        Command::CancelListing { mints } => commands::cancel_listing(&context()?, &mints),
+        Command::Buy { mint, price } => commands::buy(&context()?, &mint, price),
+        Command::Bid { target, price, collection } => commands::bid(&context()?, &target, price, collection),
+        Command::CancelBid { targets } => commands::cancel_bids(&context()?, &targets),
+        Command::AcceptBid { mint, bidder, collection, price } => {
+            commands::accept_bid(&context()?, &mint, &bidder, collection.as_ref(), price)
+        }
+        Command::CreateAuction { mint, reserve_price, min_increment, start_in, duration, dutch_start_price } => {
+            let schedule = commands::AuctionSchedule { start_in, duration, dutch_start_price };
+            commands::create_auction(&context()?, &mint, reserve_price, min_increment, schedule)
+        }
+        Command::AuctionBid { mint, amount } => commands::auction_bid(&context()?, &mint, amount),
+        Command::SettleAuction { mints } => commands::settle_auctions(&context()?, &mints),
+        Command::ConfigureReveal { collection, placeholder_uri, provenance_hash } => {
+            commands::configure_reveal(&context()?, &collection, placeholder_uri, provenance_hash)
+        }
+        Command::Reveal { collection, base_uri, mints } => commands::reveal(&context()?, &collection, &base_uri, &mints),
+        Command::LoadPool { collection, manifest } => commands::load_pool(&context()?, &collection, &manifest),
+        Command::MintFromPool { collection, count } => commands::mint_from_pool(&context()?, &collection, count),
+        Command::SetTemplate { collection, spec } => commands::set_template(&context()?, &collection, &spec),
+        Command::MintNext { collection, count, lite } => commands::mint_next(&context()?, &collection, count, lite),
+        Command::ValidateDrop { manifest } => drop::validate(&manifest),
+        Command::Drop { collection, manifest, cache, verify, lite } => {
+            drop::run(&context()?, &collection, &manifest, cache.as_deref(), verify, lite)
+        }
+        Command::MigrateCollection { mint, next_index } => commands::migrate_collection(&context()?, &mint, next_index),
+        Command::MigrateNft { mints, collection } => commands::migrate_nfts(&context()?, &mints, collection),
+        Command::ShowCollection { mint } => commands::show_collection(&RpcClient::new(&cli.url), &mint),
+        Command::ShowAncestry { mint } => commands::show_ancestry(&RpcClient::new(&cli.url), &mint),
+        Command::ShowNft { mint } => commands::show_nft(&RpcClient::new(&cli.url), &mint),
+    }
+}
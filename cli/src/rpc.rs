@@ -0,0 +1,122 @@
+use std::{str::FromStr, thread, time::Duration};
+
+use anyhow::{anyhow, bail, Context, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde_json::{json, Value};
+use solana_sdk::{hash::Hash, pubkey::Pubkey, signature::Signature, transaction::Transaction};
+
+const CONFIRM_POLL_INTERVAL: Duration = Duration::from_millis(500);
+const CONFIRM_ATTEMPTS: usize = 120;
+
+/// Minimal blocking JSON RPC client covering what the CLI needs.
+pub struct RpcClient {
+    url: String,
+    agent: ureq::Agent,
+}
+
+impl RpcClient {
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            agent: ureq::AgentBuilder::new().timeout(Duration::from_secs(30)).build(),
+        }
+    }
+
+    fn call(&self, method: &str, params: Value) -> Result<Value> {
+        let response: Value = self
+            .agent
+            .post(&self.url)
+            .send_json(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": method,
+                "params": params,
+            }))
+            .with_context(|| format!("{method} request to {} failed", self.url))?
+            .into_json()?;
+
+        if let Some(error) = response.get("error") {
+            bail!("{method} failed: {error}");
+        }
+        response
+            .get("result")
+            .cloned()
+            .ok_or_else(|| anyhow!("{method} returned no result"))
+    }
+
+    pub fn get_latest_blockhash(&self) -> Result<Hash> {
+        let result = self.call("getLatestBlockhash", json!([{ "commitment": "confirmed" }]))?;
+        let blockhash = result["value"]["blockhash"]
+            .as_str()
+            .ok_or_else(|| anyhow!("malformed getLatestBlockhash response"))?;
+        Ok(Hash::from_str(blockhash)?)
+    }
+
+    pub fn get_account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>> {
+        let result = self.call(
+            "getAccountInfo",
+            json!([address.to_string(), { "encoding": "base64", "commitment": "confirmed" }]),
+        )?;
+        let value = &result["value"];
+        if value.is_null() {
+            return Ok(None);
+        }
+        let data = value["data"][0]
+            .as_str()
+            .ok_or_else(|| anyhow!("malformed getAccountInfo response"))?;
+        Ok(Some(STANDARD.decode(data)?))
+    }
+
+    pub fn get_minimum_balance_for_rent_exemption(&self, space: usize) -> Result<u64> {
+        let result = self.call("getMinimumBalanceForRentExemption", json!([space]))?;
+        result
+            .as_u64()
+            .ok_or_else(|| anyhow!("malformed getMinimumBalanceForRentExemption response"))
+    }
+
+    pub fn get_account_owner(&self, address: &Pubkey) -> Result<Option<Pubkey>> {
+        let result = self.call(
+            "getAccountInfo",
+            json!([address.to_string(), { "encoding": "base64", "commitment": "confirmed" }]),
+        )?;
+        let value = &result["value"];
+        if value.is_null() {
+            return Ok(None);
+        }
+        let owner = value["owner"]
+            .as_str()
+            .ok_or_else(|| anyhow!("malformed getAccountInfo response"))?;
+        Ok(Some(Pubkey::from_str(owner)?))
+    }
+
+    pub fn send_and_confirm_transaction(&self, transaction: &Transaction) -> Result<Signature> {
+        let encoded = STANDARD.encode(bincode::serialize(transaction)?);
+        let signature = self.call(
+            "sendTransaction",
+            json!([encoded, { "encoding": "base64", "preflightCommitment": "confirmed" }]),
+        )?;
+        let signature = Signature::from_str(
+            signature.as_str().ok_or_else(|| anyhow!("malformed sendTransaction response"))?,
+        )?;
+
+        for _ in 0..CONFIRM_ATTEMPTS {
+            let result = self.call(
+                "getSignatureStatuses",
+                json!([[signature.to_string()], { "searchTransactionHistory": false }]),
+            )?;
+            let status = &result["value"][0];
+            if !status.is_null() {
+                if !status["err"].is_null() {
+                    bail!("transaction {signature} failed: {}", status["err"]);
+                }
+                let confirmation = status["confirmationStatus"].as_str().unwrap_or_default();
+                if confirmation == "confirmed" || confirmation == "finalized" {
+                    return Ok(signature);
+                }
+            }
+            thread::sleep(CONFIRM_POLL_INTERVAL);
+        }
+
+        bail!("timed out waiting for transaction {signature} to confirm")
+    }
+}
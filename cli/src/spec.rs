@@ -0,0 +1,208 @@
+use std::{fs, path::Path, str::FromStr};
+
+use anyhow::{Context, Result};
+use serde::{de::DeserializeOwned, Deserialize};
+use solana_sdk::pubkey::Pubkey;
+
+use nft_program_client::{CollectionData, CreatorData, MintTemplateData, NftData, UseMethodData, UsesData};
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct CreatorSpec {
+    pub address: String,
+    #[serde(default)]
+    pub verified: bool,
+    pub share: u8,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct CollectionSpec {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub seller_fee_basis_points: u16,
+    pub creators: Vec<CreatorSpec>,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum UseMethodSpec {
+    Burn,
+    Multiple,
+    Single,
+}
+
+/// Makes an item consumable through `use-nft`, e.g. `{ "method": "burn", "total": 3 }`.
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct UsesSpec {
+    pub method: UseMethodSpec,
+    pub total: u64,
+}
+
+impl From<UsesSpec> for UsesData {
+    fn from(spec: UsesSpec) -> Self {
+        UsesData {
+            use_method: match spec.method {
+                UseMethodSpec::Burn => UseMethodData::Burn,
+                UseMethodSpec::Multiple => UseMethodData::Multiple,
+                UseMethodSpec::Single => UseMethodData::Single,
+            },
+            total: spec.total,
+        }
+    }
+}
+
+/// Royalties left out are inherited from the collection defaults set by `set-royalties`.
+#[derive(Deserialize, Debug, Clone)]
+pub struct NftSpec {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    #[serde(default)]
+    pub seller_fee_basis_points: Option<u16>,
+    #[serde(default)]
+    pub creators: Option<Vec<CreatorSpec>>,
+    #[serde(default)]
+    pub uses: Option<UsesSpec>,
+}
+
+/// Template `mint-next` renders items from, e.g. `"Cool Cat #"` and `"https://example.com/"`
+/// with a `".json"` suffix.
+#[derive(Deserialize, Debug, Clone)]
+pub struct TemplateSpec {
+    pub name_prefix: String,
+    pub uri_prefix: String,
+    #[serde(default)]
+    pub uri_suffix: String,
+    pub seller_fee_basis_points: u16,
+    pub creators: Vec<CreatorSpec>,
+}
+
+/// Royalties `set-royalties` makes the collection default, optionally enforced on every item.
+#[derive(Deserialize, Debug, Clone)]
+pub struct RoyaltySpec {
+    pub seller_fee_basis_points: u16,
+    pub creators: Vec<CreatorSpec>,
+    #[serde(default)]
+    pub enforce: bool,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Manifest {
+    pub nfts: Vec<NftSpec>,
+}
+
+/// Reads `path` as TOML when it has a `.toml` extension and as JSON otherwise.
+pub fn load<T: DeserializeOwned>(path: &Path) -> Result<T> {
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+
+    let parsed = match path.extension().and_then(|ext| ext.to_str()) {
+        Some("toml") => toml::from_str(&contents).map_err(anyhow::Error::from),
+        _ => serde_json::from_str(&contents).map_err(anyhow::Error::from),
+    };
+    parsed.with_context(|| format!("failed to parse {}", path.display()))
+}
+
+fn creators(creators: &[CreatorSpec]) -> Result<Vec<CreatorData>> {
+    creators
+        .iter()
+        .map(|creator| {
+            Ok(CreatorData {
+                address: Pubkey::from_str(&creator.address)
+                    .with_context(|| format!("invalid creator address {}", creator.address))?,
+                verified: creator.verified,
+                share: creator.share,
+            })
+        })
+        .collect()
+}
+
+impl CollectionSpec {
+    pub fn to_collection_data(&self) -> Result<CollectionData> {
+        Ok(CollectionData {
+            name: self.name.clone(),
+            symbol: self.symbol.clone(),
+            uri: self.uri.clone(),
+            seller_fee_basis_points: self.seller_fee_basis_points,
+            creators: creators(&self.creators)?,
+        })
+    }
+}
+
+impl NftSpec {
+    pub fn to_nft_data(&self) -> Result<NftData> {
+        Ok(NftData {
+            name: self.name.clone(),
+            symbol: self.symbol.clone(),
+            uri: self.uri.clone(),
+            seller_fee_basis_points: self.seller_fee_basis_points,
+            creators: self.creators.as_deref().map(creators).transpose()?,
+            uses: self.uses.map(UsesData::from),
+        })
+    }
+}
+
+impl RoyaltySpec {
+    pub fn to_creator_data(&self) -> Result<Vec<CreatorData>> {
+        creators(&self.creators)
+    }
+}
+
+impl TemplateSpec {
+    pub fn to_template_data(&self) -> Result<MintTemplateData> {
+        Ok(MintTemplateData {
+            name_prefix: self.name_prefix.clone(),
+            uri_prefix: self.uri_prefix.clone(),
+            uri_suffix: self.uri_suffix.clone(),
+            seller_fee_basis_points: self.seller_fee_basis_points,
+            creators: creators(&self.creators)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uses_and_optional_royalties_parse() {
+        let spec: NftSpec = serde_json::from_str(
+            r#"{ "name": "Ticket", "symbol": "TNFT", "uri": "https://example.com/t.json", "uses": { "method": "burn", "total": 3 } }"#,
+        )
+        .unwrap();
+        let data = spec.to_nft_data().unwrap();
+
+        assert!(data.seller_fee_basis_points.is_none());
+        assert!(data.creators.is_none());
+        let uses = data.uses.unwrap();
+        assert_eq!(uses.use_method, UseMethodData::Burn);
+        assert_eq!(uses.total, 3);
+    }
+
+    #[test]
+    fn unknown_use_method_is_rejected() {
+        let err = serde_json::from_str::<UsesSpec>(r#"{ "method": "twice", "total": 2 }"#).unwrap_err();
+        assert!(err.to_string().contains("unknown variant"), "{err}");
+    }
+
+    #[test]
+    fn invalid_creator_address_is_rejected() {
+        let spec: RoyaltySpec = toml::from_str(
+            "seller_fee_basis_points = 500\n[[creators]]\naddress = \"not-a-pubkey\"\nshare = 100\n",
+        )
+        .unwrap();
+        let err = spec.to_creator_data().unwrap_err();
+        assert!(err.to_string().contains("invalid creator address not-a-pubkey"), "{err}");
+        assert!(!spec.enforce);
+    }
+
+    #[test]
+    fn load_reports_the_file_it_failed_to_parse() {
+        let path = std::env::temp_dir().join(format!("nft-cli-{}-bad-manifest.toml", std::process::id()));
+        fs::write(&path, "nfts = 3\n").unwrap();
+        let err = load::<Manifest>(&path).unwrap_err();
+        fs::remove_file(&path).unwrap();
+
+        assert!(err.to_string().starts_with("failed to parse"), "{err}");
+    }
+}
@@ -0,0 +1,81 @@
+use anchor_lang::{prelude::Pubkey, AccountDeserialize, Result};
+use anchor_spl::metadata::MetadataAccount;
+use nft_program::{
+    Auction, Bid, CollectionInfo, ItemPool, Listing, MintTemplate, NftInfo, RentalAgreement, SoulboundRecord, StakeRecord,
+    StakingPool,
+};
+
+pub fn decode_collection_info(mut data: &[u8]) -> Result<CollectionInfo> {
+    CollectionInfo::try_deserialize(&mut data)
+}
+
+pub fn decode_nft_info(mut data: &[u8]) -> Result<NftInfo> {
+    NftInfo::try_deserialize(&mut data)
+}
+
+pub fn decode_staking_pool(mut data: &[u8]) -> Result<StakingPool> {
+    StakingPool::try_deserialize(&mut data)
+}
+
+pub fn decode_stake_record(mut data: &[u8]) -> Result<StakeRecord> {
+    StakeRecord::try_deserialize(&mut data)
+}
+
+pub fn decode_rental_agreement(mut data: &[u8]) -> Result<RentalAgreement> {
+    RentalAgreement::try_deserialize(&mut data)
+}
+
+pub fn decode_listing(mut data: &[u8]) -> Result<Listing> {
+    Listing::try_deserialize(&mut data)
+}
+
+pub fn decode_auction(mut data: &[u8]) -> Result<Auction> {
+    Auction::try_deserialize(&mut data)
+}
+
+pub fn decode_bid(mut data: &[u8]) -> Result<Bid> {
+    Bid::try_deserialize(&mut data)
+}
+
+/// Decodes the header of an `ItemPool`, the bitmap and items following it are left out.
+pub fn decode_item_pool(mut data: &[u8]) -> Result<ItemPool> {
+    ItemPool::try_deserialize(&mut data)
+}
+
+pub fn decode_mint_template(mut data: &[u8]) -> Result<MintTemplate> {
+    MintTemplate::try_deserialize(&mut data)
+}
+
+pub fn decode_soulbound_record(mut data: &[u8]) -> Result<SoulboundRecord> {
+    SoulboundRecord::try_deserialize(&mut data)
+}
+
+/// Decodes a Token Metadata metadata account, e.g. to find the creators [`crate::buy_nft`] pays.
+pub fn decode_metadata(mut data: &[u8]) -> Result<MetadataAccount> {
+    MetadataAccount::try_deserialize(&mut data)
+}
+
+/// Walks up from `collection_mint` through each `CollectionInfo.parent`, returning the
+/// collection itself first and the top-level collection last. `fetch` returns the data of an
+/// account, or `None` when it does not exist.
+pub fn collection_ancestry<F, E>(collection_mint: &Pubkey, mut fetch: F) -> std::result::Result<Vec<CollectionInfo>, E>
+where
+    F: FnMut(&Pubkey) -> std::result::Result<Option<Vec<u8>>, E>,
+    E: From<anchor_lang::error::Error>,
+{
+    let mut ancestry: Vec<CollectionInfo> = Vec::new();
+    let mut next = Some(*collection_mint);
+    while let Some(mint) = next {
+        // Parents are created before their children, a repeated mint means corrupt data.
+        if ancestry.iter().any(|info| info.mint == mint) {
+            break;
+        }
+        let Some(data) = fetch(&crate::find_collection_info(&mint).0)? else {
+            break;
+        };
+        let info = decode_collection_info(&data)?;
+        next = info.parent;
+        ancestry.push(info);
+    }
+    Ok(ancestry)
+}
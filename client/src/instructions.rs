@@ -0,0 +1,1523 @@
+use anchor_lang::{
+    prelude::Pubkey,
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        sysvar,
+    },
+    system_program,
+    InstructionData,
+    ToAccountMetas,
+};
+use anchor_spl::{associated_token, token, token_2022};
+use nft_program::{
+    accounts,
+    instruction,
+    AuctionParams,
+    CollectionData,
+    CreatorData,
+    MintTemplateData,
+    NftData,
+    PoolItem,
+    ACCOUNT_COMPRESSION_ID,
+    BUBBLEGUM_ID,
+    MPL_CORE_ID,
+    NOOP_ID,
+};
+use solana_system_interface::instruction as system_instruction;
+
+use crate::pda::*;
+
+/// Builds `create_collection_instruction`, optionally committing to a `provenance_hash`.
+/// `mint` must also sign the transaction.
+pub fn create_collection(
+    user: Pubkey,
+    mint: Pubkey,
+    collection_data: CollectionData,
+    provenance_hash: Option<[u8; 32]>,
+) -> Instruction {
+    build_create_collection(user, mint, collection_data, provenance_hash, None, token::ID)
+}
+
+/// Same as [`create_collection`] for a mint owned by `token_program`, SPL Token or Token-2022.
+pub fn create_collection_with_token_program(
+    user: Pubkey,
+    mint: Pubkey,
+    collection_data: CollectionData,
+    provenance_hash: Option<[u8; 32]>,
+    token_program: Pubkey,
+) -> Instruction {
+    build_create_collection(user, mint, collection_data, provenance_hash, None, token_program)
+}
+
+/// Same as [`create_collection`], verifying the new collection into `parent_collection_mint`,
+/// a Metaplex collection created by `user` through this program.
+pub fn create_child_collection(
+    user: Pubkey,
+    mint: Pubkey,
+    collection_data: CollectionData,
+    provenance_hash: Option<[u8; 32]>,
+    parent_collection_mint: Pubkey,
+) -> Instruction {
+    build_create_collection(user, mint, collection_data, provenance_hash, Some(parent_collection_mint), token::ID)
+}
+
+fn build_create_collection(
+    user: Pubkey,
+    mint: Pubkey,
+    collection_data: CollectionData,
+    provenance_hash: Option<[u8; 32]>,
+    parent_collection_mint: Option<Pubkey>,
+    token_program: Pubkey,
+) -> Instruction {
+    let accounts = accounts::CreateCollection {
+        user,
+        mint,
+        mint_authority: find_mint_authority().0,
+        collection_info: find_collection_info(&mint).0,
+        metadata: find_metadata(&mint).0,
+        master_edition: find_master_edition(&mint).0,
+        destination: find_token_account(&user, &mint, &token_program),
+        slot_hashes: provenance_hash.map(|_| sysvar::slot_hashes::ID),
+        parent_collection_mint,
+        parent_collection_info: parent_collection_mint.map(|parent| find_collection_info(&parent).0),
+        parent_metadata: parent_collection_mint.map(|parent| find_metadata(&parent).0),
+        parent_master_edition: parent_collection_mint.map(|parent| find_master_edition(&parent).0),
+        sysvar_instruction: parent_collection_mint.map(|_| sysvar::instructions::ID),
+        system_program: system_program::ID,
+        token_program,
+        associated_token_program: associated_token::ID,
+        token_metadata_program: token_metadata_program_id(),
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::CreateCollectionInstruction { collection_data, provenance_hash }.data(),
+    }
+}
+
+/// Builds `create_collection_token_2022_instruction`, a Token-2022 group collection holding up to
+/// `max_size` items. `mint` must also sign the transaction.
+pub fn create_collection_token_2022(
+    user: Pubkey,
+    mint: Pubkey,
+    collection_data: CollectionData,
+    max_size: u64,
+) -> Instruction {
+    let accounts = accounts::CreateCollectionToken2022 {
+        user,
+        mint,
+        mint_authority: find_mint_authority().0,
+        collection_info: find_collection_info(&mint).0,
+        destination: find_token_account(&user, &mint, &token_2022::ID),
+        system_program: system_program::ID,
+        token_program: token_2022::ID,
+        associated_token_program: associated_token::ID,
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::CreateCollectionToken2022Instruction { collection_data, max_size }.data(),
+    }
+}
+
+/// Builds `create_collection_core_instruction`, a Metaplex Core collection.
+/// `collection` must also sign the transaction.
+pub fn create_collection_core(user: Pubkey, collection: Pubkey, collection_data: CollectionData) -> Instruction {
+    let accounts = accounts::CreateCollectionCore {
+        user,
+        collection,
+        mint_authority: find_mint_authority().0,
+        collection_info: find_collection_info(&collection).0,
+        system_program: system_program::ID,
+        mpl_core_program: MPL_CORE_ID,
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::CreateCollectionCoreInstruction { collection_data }.data(),
+    }
+}
+
+/// Size of an account compression Merkle tree account, including the concurrent Merkle tree
+/// header and a canopy of `canopy_depth` levels.
+pub fn merkle_tree_account_size(max_depth: u32, max_buffer_size: u32, canopy_depth: u32) -> usize {
+    const HEADER_SIZE: usize = 56;
+    const TREE_COUNTERS_SIZE: usize = 24;
+
+    let (max_depth, max_buffer_size) = (max_depth as usize, max_buffer_size as usize);
+    let change_log_size = 32 + 32 * max_depth + 8;
+    let rightmost_path_size = 32 * max_depth + 40;
+    let canopy_size = ((1usize << (canopy_depth + 1)) - 2) * 32;
+
+    HEADER_SIZE + TREE_COUNTERS_SIZE + max_buffer_size * change_log_size + rightmost_path_size + canopy_size
+}
+
+/// Allocates `merkle_tree` for the account compression program. Must precede
+/// [`create_compressed_tree`]; `merkle_tree` signs the transaction and `lamports` should cover
+/// rent for `space`, usually [`merkle_tree_account_size`].
+pub fn allocate_merkle_tree(payer: Pubkey, merkle_tree: Pubkey, lamports: u64, space: usize) -> Instruction {
+    system_instruction::create_account(&payer, &merkle_tree, lamports, space as u64, &ACCOUNT_COMPRESSION_ID)
+}
+
+/// Builds `create_compressed_tree_instruction`, making `merkle_tree` the collection's Bubblegum tree.
+pub fn create_compressed_tree(
+    creator: Pubkey,
+    collection_mint: Pubkey,
+    merkle_tree: Pubkey,
+    max_depth: u32,
+    max_buffer_size: u32,
+) -> Instruction {
+    let accounts = accounts::CreateCompressedTree {
+        creator,
+        collection_mint,
+        collection_info: find_collection_info(&collection_mint).0,
+        merkle_tree,
+        tree_authority: find_tree_authority(&merkle_tree).0,
+        mint_authority: find_mint_authority().0,
+        system_program: system_program::ID,
+        bubblegum_program: BUBBLEGUM_ID,
+        log_wrapper: NOOP_ID,
+        compression_program: ACCOUNT_COMPRESSION_ID,
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::CreateCompressedTreeInstruction { max_depth, max_buffer_size }.data(),
+    }
+}
+
+/// Builds `mint_compressed_nft_instruction`, appending a compressed NFT owned by `leaf_owner`
+/// to the collection's `merkle_tree`.
+pub fn mint_compressed_nft(
+    payer: Pubkey,
+    leaf_owner: Pubkey,
+    collection_mint: Pubkey,
+    merkle_tree: Pubkey,
+    nft_data: NftData,
+) -> Instruction {
+    let accounts = accounts::MintCompressedNFT {
+        payer,
+        leaf_owner,
+        collection_mint,
+        collection_info: find_collection_info(&collection_mint).0,
+        collection_metadata: find_metadata(&collection_mint).0,
+        collection_master_edition: find_master_edition(&collection_mint).0,
+        merkle_tree,
+        tree_authority: find_tree_authority(&merkle_tree).0,
+        mint_authority: find_mint_authority().0,
+        bubblegum_signer: find_bubblegum_signer().0,
+        system_program: system_program::ID,
+        token_metadata_program: token_metadata_program_id(),
+        bubblegum_program: BUBBLEGUM_ID,
+        log_wrapper: NOOP_ID,
+        compression_program: ACCOUNT_COMPRESSION_ID,
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::MintCompressedNftInstruction { nft_data }.data(),
+    }
+}
+
+/// Builds `mint_nft_instruction`. `mint` must also sign the transaction.
+pub fn mint_nft(owner: Pubkey, mint: Pubkey, collection_mint: Pubkey, nft_data: NftData) -> Instruction {
+    build_mint_nft(owner, mint, collection_mint, nft_data, Some(find_nft_info(&mint).0), false, token::ID)
+}
+
+/// Builds `mint_nft_instruction` in lite mode, without an `NftInfo` account.
+pub fn mint_nft_lite(owner: Pubkey, mint: Pubkey, collection_mint: Pubkey, nft_data: NftData) -> Instruction {
+    build_mint_nft(owner, mint, collection_mint, nft_data, None, false, token::ID)
+}
+
+/// Builds `mint_nft_instruction` for a soulbound NFT, frozen in the owner's wallet until the
+/// collection creator runs [`revoke_soulbound`].
+pub fn mint_soulbound_nft(owner: Pubkey, mint: Pubkey, collection_mint: Pubkey, nft_data: NftData) -> Instruction {
+    build_mint_nft(owner, mint, collection_mint, nft_data, Some(find_nft_info(&mint).0), true, token::ID)
+}
+
+/// Same as [`mint_nft`] for a mint owned by `token_program`, SPL Token or Token-2022.
+pub fn mint_nft_with_token_program(
+    owner: Pubkey,
+    mint: Pubkey,
+    collection_mint: Pubkey,
+    nft_data: NftData,
+    token_program: Pubkey,
+) -> Instruction {
+    build_mint_nft(owner, mint, collection_mint, nft_data, Some(find_nft_info(&mint).0), false, token_program)
+}
+
+fn build_mint_nft(
+    owner: Pubkey,
+    mint: Pubkey,
+    collection_mint: Pubkey,
+    nft_data: NftData,
+    nft_info: Option<Pubkey>,
+    soulbound: bool,
+    token_program: Pubkey,
+) -> Instruction {
+    let accounts = accounts::MintNFT {
+        owner,
+        mint,
+        destination: find_token_account(&owner, &mint, &token_program),
+        mint_authority: find_mint_authority().0,
+        collection_info: find_collection_info(&collection_mint).0,
+        nft_info,
+        soulbound_record: soulbound.then(|| find_soulbound_record(&mint).0),
+        metadata: find_metadata(&mint).0,
+        master_edition: find_master_edition(&mint).0,
+        collection_mint,
+        system_program: system_program::ID,
+        token_program,
+        associated_token_program: associated_token::ID,
+        token_metadata_program: token_metadata_program_id(),
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::MintNftInstruction { nft_data }.data(),
+    }
+}
+
+/// Builds `mint_nft_token_2022_instruction`, a Token-2022 NFT carrying its own metadata and
+/// joining `collection_mint` as a token-group member.
+/// `mint` must also sign the transaction.
+pub fn mint_nft_token_2022(
+    owner: Pubkey,
+    mint: Pubkey,
+    collection_mint: Pubkey,
+    nft_data: NftData,
+    lite: bool,
+) -> Instruction {
+    let accounts = accounts::MintNFTToken2022 {
+        owner,
+        mint,
+        destination: find_token_account(&owner, &mint, &token_2022::ID),
+        mint_authority: find_mint_authority().0,
+        nft_info: (!lite).then(|| find_nft_info(&mint).0),
+        collection_mint,
+        collection_info: find_collection_info(&collection_mint).0,
+        system_program: system_program::ID,
+        token_program: token_2022::ID,
+        associated_token_program: associated_token::ID,
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::MintNftToken2022Instruction { nft_data }.data(),
+    }
+}
+
+/// Builds `mint_nft_core_instruction`, a Metaplex Core asset inside the Core `collection`.
+/// `asset` must also sign the transaction.
+pub fn mint_nft_core(owner: Pubkey, asset: Pubkey, collection: Pubkey, nft_data: NftData, lite: bool) -> Instruction {
+    let accounts = accounts::MintNFTCore {
+        owner,
+        asset,
+        mint_authority: find_mint_authority().0,
+        nft_info: (!lite).then(|| find_nft_info(&asset).0),
+        collection,
+        collection_info: find_collection_info(&collection).0,
+        system_program: system_program::ID,
+        mpl_core_program: MPL_CORE_ID,
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::MintNftCoreInstruction { nft_data }.data(),
+    }
+}
+
+/// Builds `mint_pnft_instruction`, a programmable NFT optionally bound to the `rule_set`.
+/// `mint` must also sign the transaction.
+pub fn mint_pnft(
+    owner: Pubkey,
+    mint: Pubkey,
+    collection_mint: Pubkey,
+    nft_data: NftData,
+    rule_set: Option<Pubkey>,
+    lite: bool,
+) -> Instruction {
+    let destination = find_token_account(&owner, &mint, &token::ID);
+    let accounts = accounts::MintPNFT {
+        owner,
+        mint,
+        destination,
+        mint_authority: find_mint_authority().0,
+        nft_info: (!lite).then(|| find_nft_info(&mint).0),
+        metadata: find_metadata(&mint).0,
+        master_edition: find_master_edition(&mint).0,
+        token_record: find_token_record(&mint, &destination).0,
+        collection_mint,
+        collection_info: find_collection_info(&collection_mint).0,
+        authorization_rules: rule_set,
+        authorization_rules_program: rule_set.map(|_| TOKEN_AUTH_RULES_PROGRAM_ID),
+        system_program: system_program::ID,
+        sysvar_instruction: sysvar::instructions::ID,
+        token_program: token::ID,
+        associated_token_program: associated_token::ID,
+        token_metadata_program: token_metadata_program_id(),
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::MintPnftInstruction { nft_data }.data(),
+    }
+}
+
+/// Optional accounts of [`transfer_nft`] and [`burn_nft`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TokenMetadataOptions {
+    /// Set for pNFTs so token records are passed.
+    pub programmable: bool,
+    /// Rule set the pNFT was minted with.
+    pub rule_set: Option<Pubkey>,
+    /// Set when the NFT has no `NftInfo` account.
+    pub lite: bool,
+}
+
+/// Builds `transfer_nft_instruction`, moving `mint` from `owner` to `recipient` through Token Metadata.
+pub fn transfer_nft(owner: Pubkey, recipient: Pubkey, mint: Pubkey, options: TokenMetadataOptions) -> Instruction {
+    let token = find_token_account(&owner, &mint, &token::ID);
+    let recipient_token = find_token_account(&recipient, &mint, &token::ID);
+    let accounts = accounts::TransferNFT {
+        owner,
+        token,
+        recipient,
+        recipient_token,
+        mint,
+        metadata: find_metadata(&mint).0,
+        edition: find_master_edition(&mint).0,
+        token_record: options.programmable.then(|| find_token_record(&mint, &token).0),
+        recipient_token_record: options.programmable.then(|| find_token_record(&mint, &recipient_token).0),
+        nft_info: (!options.lite).then(|| find_nft_info(&mint).0),
+        authorization_rules: options.rule_set,
+        authorization_rules_program: options.rule_set.map(|_| TOKEN_AUTH_RULES_PROGRAM_ID),
+        system_program: system_program::ID,
+        sysvar_instruction: sysvar::instructions::ID,
+        token_program: token::ID,
+        associated_token_program: associated_token::ID,
+        token_metadata_program: token_metadata_program_id(),
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::TransferNftInstruction {}.data(),
+    }
+}
+
+/// Builds `burn_nft_instruction`. Pass `collection_mint` for verified items so the collection
+/// size and `CollectionInfo` count are decremented.
+pub fn burn_nft(
+    owner: Pubkey,
+    mint: Pubkey,
+    collection_mint: Option<Pubkey>,
+    options: TokenMetadataOptions,
+) -> Instruction {
+    let token = find_token_account(&owner, &mint, &token::ID);
+    let accounts = accounts::BurnNFT {
+        owner,
+        token,
+        mint,
+        metadata: find_metadata(&mint).0,
+        edition: find_master_edition(&mint).0,
+        token_record: options.programmable.then(|| find_token_record(&mint, &token).0),
+        collection_metadata: collection_mint.map(|collection_mint| find_metadata(&collection_mint).0),
+        nft_info: (!options.lite).then(|| find_nft_info(&mint).0),
+        collection_info: collection_mint.map(|collection_mint| find_collection_info(&collection_mint).0),
+        stake_record: find_stake_record(&mint).0,
+        rental_agreement: find_rental_agreement(&mint).0,
+        listing: find_listing(&mint).0,
+        auction: find_auction(&mint).0,
+        soulbound_record: find_soulbound_record(&mint).0,
+        system_program: system_program::ID,
+        sysvar_instruction: sysvar::instructions::ID,
+        token_program: token::ID,
+        token_metadata_program: token_metadata_program_id(),
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::BurnNftInstruction {}.data(),
+    }
+}
+
+/// Builds `use_nft_instruction`, signed by `authority`: the holder `owner` or the use authority
+/// they approved. Pass `collection_mint` for verified items whose last use burns them.
+pub fn use_nft(authority: Pubkey, owner: Pubkey, mint: Pubkey, collection_mint: Option<Pubkey>) -> Instruction {
+    let accounts = accounts::UseNFT {
+        authority,
+        owner,
+        token: find_token_account(&owner, &mint, &token::ID),
+        mint,
+        metadata: find_metadata(&mint).0,
+        edition: find_master_edition(&mint).0,
+        nft_info: find_nft_info(&mint).0,
+        collection_metadata: collection_mint.map(|collection_mint| find_metadata(&collection_mint).0),
+        collection_info: collection_mint.map(|collection_mint| find_collection_info(&collection_mint).0),
+        stake_record: find_stake_record(&mint).0,
+        rental_agreement: find_rental_agreement(&mint).0,
+        listing: find_listing(&mint).0,
+        auction: find_auction(&mint).0,
+        soulbound_record: find_soulbound_record(&mint).0,
+        system_program: system_program::ID,
+        sysvar_instruction: sysvar::instructions::ID,
+        token_program: token::ID,
+        token_metadata_program: token_metadata_program_id(),
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::UseNftInstruction {}.data(),
+    }
+}
+
+/// Builds `set_use_authority_instruction`, signed by the holder `owner`. `None` revokes the
+/// current use authority.
+pub fn set_use_authority(owner: Pubkey, mint: Pubkey, use_authority: Option<Pubkey>) -> Instruction {
+    let accounts = accounts::SetUseAuthority {
+        owner,
+        token: find_token_account(&owner, &mint, &token::ID),
+        mint,
+        nft_info: find_nft_info(&mint).0,
+        token_program: token::ID,
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::SetUseAuthorityInstruction { use_authority }.data(),
+    }
+}
+
+/// Builds `revoke_soulbound_instruction`, signed by the creator of the NFT's collection, which
+/// burns the soulbound NFT from `owner`'s wallet.
+pub fn revoke_soulbound(creator: Pubkey, owner: Pubkey, mint: Pubkey, collection_mint: Pubkey, lite: bool) -> Instruction {
+    let accounts = accounts::RevokeSoulbound {
+        creator,
+        owner,
+        token: find_token_account(&owner, &mint, &token::ID),
+        mint,
+        metadata: find_metadata(&mint).0,
+        edition: find_master_edition(&mint).0,
+        mint_authority: find_mint_authority().0,
+        soulbound_record: find_soulbound_record(&mint).0,
+        collection_info: find_collection_info(&collection_mint).0,
+        collection_mint,
+        collection_metadata: find_metadata(&collection_mint).0,
+        nft_info: (!lite).then(|| find_nft_info(&mint).0),
+        system_program: system_program::ID,
+        sysvar_instruction: sysvar::instructions::ID,
+        token_program: token::ID,
+        token_metadata_program: token_metadata_program_id(),
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::RevokeSoulboundInstruction {}.data(),
+    }
+}
+
+/// Builds `freeze_nft_instruction`, locking `mint` in the owner's token account. Set
+/// `token_metadata` for NFTs with a Token Metadata master edition, which freeze through it.
+pub fn freeze_nft(owner: Pubkey, mint: Pubkey, token_program: Pubkey, token_metadata: bool, lite: bool) -> Instruction {
+    build_nft_freeze(owner, mint, token_program, token_metadata, lite, instruction::FreezeNftInstruction {}.data())
+}
+
+/// Builds `thaw_nft_instruction`, the reverse of [`freeze_nft`].
+pub fn thaw_nft(owner: Pubkey, mint: Pubkey, token_program: Pubkey, token_metadata: bool, lite: bool) -> Instruction {
+    build_nft_freeze(owner, mint, token_program, token_metadata, lite, instruction::ThawNftInstruction {}.data())
+}
+
+fn build_nft_freeze(
+    owner: Pubkey,
+    mint: Pubkey,
+    token_program: Pubkey,
+    token_metadata: bool,
+    lite: bool,
+    data: Vec<u8>,
+) -> Instruction {
+    let accounts = accounts::NftFreeze {
+        owner,
+        token: find_token_account(&owner, &mint, &token_program),
+        mint,
+        edition: token_metadata.then(|| find_master_edition(&mint).0),
+        mint_authority: find_mint_authority().0,
+        nft_info: (!lite).then(|| find_nft_info(&mint).0),
+        stake_record: find_stake_record(&mint).0,
+        rental_agreement: find_rental_agreement(&mint).0,
+        listing: find_listing(&mint).0,
+        auction: find_auction(&mint).0,
+        soulbound_record: find_soulbound_record(&mint).0,
+        token_program,
+        token_metadata_program: token_metadata_program_id(),
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data,
+    }
+}
+
+/// Builds `create_staking_pool_instruction` for a collection, paying `reward_rate` base units
+/// of `reward_mint` per staked NFT per second.
+pub fn create_staking_pool(
+    authority: Pubkey,
+    collection_mint: Pubkey,
+    reward_mint: Pubkey,
+    reward_rate: u64,
+) -> Instruction {
+    let accounts = accounts::CreateStakingPool {
+        authority,
+        collection_info: find_collection_info(&collection_mint).0,
+        reward_mint,
+        staking_pool: find_staking_pool(&collection_mint).0,
+        system_program: system_program::ID,
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::CreateStakingPoolInstruction { reward_rate }.data(),
+    }
+}
+
+/// Reward token accounts of [`unstake_nft`] and [`claim_rewards`].
+#[derive(Clone, Copy, Debug)]
+pub struct RewardOptions {
+    pub reward_mint: Pubkey,
+    pub reward_token_program: Pubkey,
+    /// Set when rewards are transferred from the pool's vault instead of minted by the pool.
+    pub from_vault: bool,
+}
+
+/// Builds `stake_nft_instruction`. Set `token_metadata` for NFTs with a Token Metadata master edition.
+pub fn stake_nft(
+    owner: Pubkey,
+    mint: Pubkey,
+    collection_mint: Pubkey,
+    token_program: Pubkey,
+    token_metadata: bool,
+) -> Instruction {
+    let accounts = accounts::StakeNFT {
+        owner,
+        token: find_token_account(&owner, &mint, &token_program),
+        mint,
+        edition: token_metadata.then(|| find_master_edition(&mint).0),
+        mint_authority: find_mint_authority().0,
+        nft_info: find_nft_info(&mint).0,
+        staking_pool: find_staking_pool(&collection_mint).0,
+        stake_record: find_stake_record(&mint).0,
+        system_program: system_program::ID,
+        token_program,
+        token_metadata_program: token_metadata_program_id(),
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::StakeNftInstruction {}.data(),
+    }
+}
+
+/// Builds `unstake_nft_instruction`, paying out pending rewards and thawing the NFT.
+pub fn unstake_nft(
+    owner: Pubkey,
+    mint: Pubkey,
+    collection_mint: Pubkey,
+    token_program: Pubkey,
+    token_metadata: bool,
+    rewards: RewardOptions,
+) -> Instruction {
+    let staking_pool = find_staking_pool(&collection_mint).0;
+    let accounts = accounts::UnstakeNFT {
+        owner,
+        token: find_token_account(&owner, &mint, &token_program),
+        mint,
+        edition: token_metadata.then(|| find_master_edition(&mint).0),
+        mint_authority: find_mint_authority().0,
+        nft_info: find_nft_info(&mint).0,
+        staking_pool,
+        stake_record: find_stake_record(&mint).0,
+        reward_mint: rewards.reward_mint,
+        reward_vault: rewards
+            .from_vault
+            .then(|| find_token_account(&staking_pool, &rewards.reward_mint, &rewards.reward_token_program)),
+        reward_destination: find_token_account(&owner, &rewards.reward_mint, &rewards.reward_token_program),
+        system_program: system_program::ID,
+        token_program,
+        reward_token_program: rewards.reward_token_program,
+        associated_token_program: associated_token::ID,
+        token_metadata_program: token_metadata_program_id(),
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::UnstakeNftInstruction {}.data(),
+    }
+}
+
+/// Builds `claim_rewards_instruction` for the staked `mint`.
+pub fn claim_rewards(owner: Pubkey, mint: Pubkey, collection_mint: Pubkey, rewards: RewardOptions) -> Instruction {
+    let staking_pool = find_staking_pool(&collection_mint).0;
+    let accounts = accounts::ClaimRewards {
+        owner,
+        staking_pool,
+        stake_record: find_stake_record(&mint).0,
+        reward_mint: rewards.reward_mint,
+        reward_vault: rewards
+            .from_vault
+            .then(|| find_token_account(&staking_pool, &rewards.reward_mint, &rewards.reward_token_program)),
+        reward_destination: find_token_account(&owner, &rewards.reward_mint, &rewards.reward_token_program),
+        system_program: system_program::ID,
+        reward_token_program: rewards.reward_token_program,
+        associated_token_program: associated_token::ID,
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::ClaimRewardsInstruction {}.data(),
+    }
+}
+
+/// Builds `rent_out_instruction`, lending `mint` to `renter` for `duration` seconds against `fee`
+/// lamports. `renter` must also sign the transaction.
+pub fn rent_out(
+    owner: Pubkey,
+    renter: Pubkey,
+    mint: Pubkey,
+    token_program: Pubkey,
+    token_metadata: bool,
+    duration: i64,
+    fee: u64,
+) -> Instruction {
+    let accounts = accounts::RentOut {
+        owner,
+        renter,
+        owner_token: find_token_account(&owner, &mint, &token_program),
+        renter_token: find_token_account(&renter, &mint, &token_program),
+        mint,
+        edition: token_metadata.then(|| find_master_edition(&mint).0),
+        mint_authority: find_mint_authority().0,
+        rental_agreement: find_rental_agreement(&mint).0,
+        system_program: system_program::ID,
+        token_program,
+        associated_token_program: associated_token::ID,
+        token_metadata_program: token_metadata_program_id(),
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::RentOutInstruction { duration, fee }.data(),
+    }
+}
+
+/// Builds `reclaim_instruction`, returning an expired rental to `owner`. Any `caller` may send it,
+/// and pays to recreate the owner's token account if it was closed during the rental.
+pub fn reclaim(
+    caller: Pubkey,
+    owner: Pubkey,
+    renter: Pubkey,
+    mint: Pubkey,
+    token_program: Pubkey,
+    token_metadata: bool,
+) -> Instruction {
+    let accounts = accounts::Reclaim {
+        caller,
+        owner,
+        renter,
+        owner_token: find_token_account(&owner, &mint, &token_program),
+        renter_token: find_token_account(&renter, &mint, &token_program),
+        mint,
+        edition: token_metadata.then(|| find_master_edition(&mint).0),
+        mint_authority: find_mint_authority().0,
+        rental_agreement: find_rental_agreement(&mint).0,
+        system_program: system_program::ID,
+        token_program,
+        associated_token_program: associated_token::ID,
+        token_metadata_program: token_metadata_program_id(),
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::ReclaimInstruction {}.data(),
+    }
+}
+
+/// Builds `list_nft_instruction`, offering a Token Metadata NFT for `price` lamports.
+pub fn list_nft(seller: Pubkey, mint: Pubkey, price: u64) -> Instruction {
+    let accounts = accounts::ListNFT {
+        seller,
+        token: find_token_account(&seller, &mint, &token::ID),
+        mint,
+        edition: Some(find_master_edition(&mint).0),
+        mint_authority: find_mint_authority().0,
+        listing: find_listing(&mint).0,
+        system_program: system_program::ID,
+        token_program: token::ID,
+        token_metadata_program: token_metadata_program_id(),
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::ListNftInstruction { price }.data(),
+    }
+}
+
+/// Builds `cancel_listing_instruction`, releasing a listed NFT back to `seller`.
+pub fn cancel_listing(seller: Pubkey, mint: Pubkey, token_program: Pubkey) -> Instruction {
+    let accounts = accounts::CancelListing {
+        seller,
+        token: find_token_account(&seller, &mint, &token_program),
+        mint,
+        edition: Some(find_master_edition(&mint).0),
+        mint_authority: find_mint_authority().0,
+        listing: find_listing(&mint).0,
+        token_program,
+        token_metadata_program: token_metadata_program_id(),
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::CancelListingInstruction {}.data(),
+    }
+}
+
+/// Builds `buy_nft_instruction` for a listing at `price`. `creators` are the metadata creators,
+/// in metadata order, who receive the royalties.
+pub fn buy_nft(
+    buyer: Pubkey,
+    seller: Pubkey,
+    mint: Pubkey,
+    token_program: Pubkey,
+    creators: &[Pubkey],
+    price: u64,
+    lite: bool,
+) -> Instruction {
+    let accounts = accounts::BuyNFT {
+        buyer,
+        seller,
+        seller_token: find_token_account(&seller, &mint, &token_program),
+        buyer_token: find_token_account(&buyer, &mint, &token_program),
+        mint,
+        metadata: find_metadata(&mint).0,
+        edition: Some(find_master_edition(&mint).0),
+        mint_authority: find_mint_authority().0,
+        nft_info: (!lite).then(|| find_nft_info(&mint).0),
+        listing: find_listing(&mint).0,
+        system_program: system_program::ID,
+        token_program,
+        associated_token_program: associated_token::ID,
+        token_metadata_program: token_metadata_program_id(),
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    let mut account_metas = accounts.to_account_metas(None);
+    account_metas.extend(creators.iter().map(|creator| AccountMeta::new(*creator, false)));
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: account_metas,
+        data: instruction::BuyNftInstruction { price }.data(),
+    }
+}
+
+/// Builds `place_bid_instruction`, escrowing `price` lamports for the NFT `target`, or for any
+/// verified NFT of the collection `target` when `collection_wide`.
+pub fn place_bid(bidder: Pubkey, target: Pubkey, price: u64, collection_wide: bool) -> Instruction {
+    let bid = find_bid(&target, &bidder).0;
+    let accounts = accounts::PlaceBid {
+        bidder,
+        target,
+        collection_info: collection_wide.then(|| find_collection_info(&target).0),
+        bid,
+        escrow: find_bid_escrow(&bid).0,
+        system_program: system_program::ID,
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::PlaceBidInstruction { price, collection_wide }.data(),
+    }
+}
+
+/// Builds `cancel_bid_instruction`, refunding the bid of `bidder` on `target`.
+pub fn cancel_bid(bidder: Pubkey, target: Pubkey) -> Instruction {
+    let bid = find_bid(&target, &bidder).0;
+    let accounts = accounts::CancelBid {
+        bidder,
+        bid,
+        escrow: find_bid_escrow(&bid).0,
+        system_program: system_program::ID,
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::CancelBidInstruction {}.data(),
+    }
+}
+
+/// Builds `accept_bid_instruction`, selling `mint` to the bid of `bidder` on `target`.
+/// `creators` are the metadata creators, in metadata order, who receive the royalties.
+#[allow(clippy::too_many_arguments)]
+pub fn accept_bid(
+    seller: Pubkey,
+    bidder: Pubkey,
+    target: Pubkey,
+    mint: Pubkey,
+    token_program: Pubkey,
+    creators: &[Pubkey],
+    price: u64,
+    lite: bool,
+) -> Instruction {
+    let bid = find_bid(&target, &bidder).0;
+    let accounts = accounts::AcceptBid {
+        seller,
+        bidder,
+        seller_token: find_token_account(&seller, &mint, &token_program),
+        bidder_token: find_token_account(&bidder, &mint, &token_program),
+        mint,
+        metadata: find_metadata(&mint).0,
+        nft_info: (!lite).then(|| find_nft_info(&mint).0),
+        bid,
+        escrow: find_bid_escrow(&bid).0,
+        system_program: system_program::ID,
+        token_program,
+        associated_token_program: associated_token::ID,
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    let mut account_metas = accounts.to_account_metas(None);
+    account_metas.extend(creators.iter().map(|creator| AccountMeta::new(*creator, false)));
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: account_metas,
+        data: instruction::AcceptBidInstruction { price }.data(),
+    }
+}
+
+/// Builds `create_auction_instruction`, auctioning a Token Metadata NFT.
+pub fn create_auction(seller: Pubkey, mint: Pubkey, params: AuctionParams) -> Instruction {
+    let auction = find_auction(&mint).0;
+    let accounts = accounts::CreateAuction {
+        seller,
+        token: find_token_account(&seller, &mint, &token::ID),
+        mint,
+        edition: Some(find_master_edition(&mint).0),
+        mint_authority: find_mint_authority().0,
+        auction,
+        escrow: find_auction_escrow(&auction).0,
+        system_program: system_program::ID,
+        token_program: token::ID,
+        token_metadata_program: token_metadata_program_id(),
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::CreateAuctionInstruction { params }.data(),
+    }
+}
+
+/// Builds `place_auction_bid_instruction`. `previous_bidder` is the auction's current top
+/// bidder, refunded by the bid.
+pub fn place_auction_bid(bidder: Pubkey, mint: Pubkey, previous_bidder: Option<Pubkey>, amount: u64) -> Instruction {
+    let auction = find_auction(&mint).0;
+    let accounts = accounts::PlaceAuctionBid {
+        bidder,
+        auction,
+        escrow: find_auction_escrow(&auction).0,
+        previous_bidder,
+        system_program: system_program::ID,
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::PlaceAuctionBidInstruction { amount }.data(),
+    }
+}
+
+/// Builds `settle_auction_instruction`. `winner` is the auction's top bidder, `None` when
+/// nobody bid; `creators` are the metadata creators, in metadata order, who receive the royalties.
+/// Without bids, the PDA delegate is only revoked when `caller` is the seller.
+pub fn settle_auction(
+    caller: Pubkey,
+    seller: Pubkey,
+    winner: Option<Pubkey>,
+    mint: Pubkey,
+    token_program: Pubkey,
+    creators: &[Pubkey],
+    lite: bool,
+) -> Instruction {
+    let auction = find_auction(&mint).0;
+    let winner = winner.unwrap_or(seller);
+    let accounts = accounts::SettleAuction {
+        caller,
+        seller,
+        winner,
+        seller_token: find_token_account(&seller, &mint, &token_program),
+        winner_token: find_token_account(&winner, &mint, &token_program),
+        mint,
+        metadata: find_metadata(&mint).0,
+        edition: Some(find_master_edition(&mint).0),
+        mint_authority: find_mint_authority().0,
+        nft_info: (!lite).then(|| find_nft_info(&mint).0),
+        auction,
+        escrow: find_auction_escrow(&auction).0,
+        system_program: system_program::ID,
+        token_program,
+        associated_token_program: associated_token::ID,
+        token_metadata_program: token_metadata_program_id(),
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    let mut account_metas = accounts.to_account_metas(None);
+    account_metas.extend(creators.iter().map(|creator| AccountMeta::new(*creator, false)));
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: account_metas,
+        data: instruction::SettleAuctionInstruction {}.data(),
+    }
+}
+
+/// Builds `configure_reveal_instruction`, run by the collection creator before the first mint.
+pub fn configure_reveal(
+    creator: Pubkey,
+    collection_mint: Pubkey,
+    placeholder_uri: String,
+    provenance_hash: [u8; 32],
+) -> Instruction {
+    let accounts = accounts::ConfigureReveal {
+        creator,
+        collection_mint,
+        collection_info: find_collection_info(&collection_mint).0,
+        slot_hashes: sysvar::slot_hashes::ID,
+        system_program: system_program::ID,
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::ConfigureRevealInstruction { placeholder_uri, provenance_hash }.data(),
+    }
+}
+
+/// Builds `reveal_instruction` for a batch of NFT `mints` of `collection_mint`.
+pub fn reveal(creator: Pubkey, collection_mint: Pubkey, base_uri: String, mints: &[Pubkey]) -> Instruction {
+    let accounts = accounts::Reveal {
+        creator,
+        collection_mint,
+        collection_info: find_collection_info(&collection_mint).0,
+        mint_authority: find_mint_authority().0,
+        system_program: system_program::ID,
+        token_metadata_program: token_metadata_program_id(),
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    let mut account_metas = accounts.to_account_metas(None);
+    for mint in mints {
+        account_metas.push(AccountMeta::new(find_nft_info(mint).0, false));
+        account_metas.push(AccountMeta::new(find_metadata(mint).0, false));
+    }
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: account_metas,
+        data: instruction::RevealInstruction { base_uri }.data(),
+    }
+}
+
+/// Builds `create_item_pool_instruction` for a pool of `capacity` items, run by the collection
+/// creator.
+pub fn create_item_pool(
+    creator: Pubkey,
+    collection_mint: Pubkey,
+    capacity: u32,
+    seller_fee_basis_points: u16,
+    creators: Vec<CreatorData>,
+) -> Instruction {
+    let accounts = accounts::CreateItemPool {
+        creator,
+        collection_mint,
+        collection_info: find_collection_info(&collection_mint).0,
+        item_pool: find_item_pool(&collection_mint).0,
+        system_program: system_program::ID,
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::CreateItemPoolInstruction { capacity, seller_fee_basis_points, creators }.data(),
+    }
+}
+
+/// Builds `add_pool_items_instruction`, uploading the next chunk of `items` into the pool.
+pub fn add_pool_items(creator: Pubkey, collection_mint: Pubkey, items: Vec<PoolItem>) -> Instruction {
+    let accounts = accounts::AddPoolItems {
+        creator,
+        item_pool: find_item_pool(&collection_mint).0,
+        system_program: system_program::ID,
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::AddPoolItemsInstruction { items }.data(),
+    }
+}
+
+/// Builds `mint_from_pool_instruction`. `mint` must also sign the transaction.
+pub fn mint_from_pool(owner: Pubkey, mint: Pubkey, collection_mint: Pubkey) -> Instruction {
+    build_mint_from_pool(owner, mint, collection_mint, false)
+}
+
+/// Builds `mint_from_pool_instruction` for a soulbound NFT, required by soulbound collections.
+pub fn mint_from_pool_soulbound(owner: Pubkey, mint: Pubkey, collection_mint: Pubkey) -> Instruction {
+    build_mint_from_pool(owner, mint, collection_mint, true)
+}
+
+fn build_mint_from_pool(owner: Pubkey, mint: Pubkey, collection_mint: Pubkey, soulbound: bool) -> Instruction {
+    let accounts = accounts::MintFromPool {
+        owner,
+        mint,
+        destination: find_token_account(&owner, &mint, &token::ID),
+        mint_authority: find_mint_authority().0,
+        collection_info: find_collection_info(&collection_mint).0,
+        item_pool: find_item_pool(&collection_mint).0,
+        nft_info: find_nft_info(&mint).0,
+        soulbound_record: soulbound.then(|| find_soulbound_record(&mint).0),
+        metadata: find_metadata(&mint).0,
+        master_edition: find_master_edition(&mint).0,
+        collection_mint,
+        slot_hashes: sysvar::slot_hashes::ID,
+        sysvar_instruction: sysvar::instructions::ID,
+        system_program: system_program::ID,
+        token_program: token::ID,
+        associated_token_program: associated_token::ID,
+        token_metadata_program: token_metadata_program_id(),
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::MintFromPoolInstruction {}.data(),
+    }
+}
+
+/// Builds `set_mint_template_instruction`, run by the collection creator.
+pub fn set_mint_template(creator: Pubkey, collection_mint: Pubkey, template: MintTemplateData) -> Instruction {
+    let accounts = accounts::SetMintTemplate {
+        creator,
+        collection_mint,
+        collection_info: find_collection_info(&collection_mint).0,
+        mint_template: find_mint_template(&collection_mint).0,
+        system_program: system_program::ID,
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::SetMintTemplateInstruction { template }.data(),
+    }
+}
+
+/// Builds `mint_next_instruction`. `mint` must also sign the transaction.
+pub fn mint_next(owner: Pubkey, mint: Pubkey, collection_mint: Pubkey) -> Instruction {
+    build_mint_next(owner, mint, collection_mint, Some(find_nft_info(&mint).0), false)
+}
+
+/// Builds `mint_next_instruction` in lite mode, without an `NftInfo` account.
+pub fn mint_next_lite(owner: Pubkey, mint: Pubkey, collection_mint: Pubkey) -> Instruction {
+    build_mint_next(owner, mint, collection_mint, None, false)
+}
+
+/// Builds `mint_next_instruction` for a soulbound NFT, required by soulbound collections.
+pub fn mint_next_soulbound(owner: Pubkey, mint: Pubkey, collection_mint: Pubkey) -> Instruction {
+    build_mint_next(owner, mint, collection_mint, Some(find_nft_info(&mint).0), true)
+}
+
+fn build_mint_next(
+    owner: Pubkey,
+    mint: Pubkey,
+    collection_mint: Pubkey,
+    nft_info: Option<Pubkey>,
+    soulbound: bool,
+) -> Instruction {
+    let accounts = accounts::MintNext {
+        owner,
+        mint,
+        destination: find_token_account(&owner, &mint, &token::ID),
+        mint_authority: find_mint_authority().0,
+        collection_info: find_collection_info(&collection_mint).0,
+        mint_template: find_mint_template(&collection_mint).0,
+        nft_info,
+        soulbound_record: soulbound.then(|| find_soulbound_record(&mint).0),
+        metadata: find_metadata(&mint).0,
+        master_edition: find_master_edition(&mint).0,
+        collection_mint,
+        system_program: system_program::ID,
+        token_program: token::ID,
+        associated_token_program: associated_token::ID,
+        token_metadata_program: token_metadata_program_id(),
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::MintNextInstruction {}.data(),
+    }
+}
+
+/// Builds `verify_collection_instruction` for `mint` against `collection_mint`.
+pub fn verify_collection(authority: Pubkey, mint: Pubkey, collection_mint: Pubkey) -> Instruction {
+    build_verify_collection(authority, mint, collection_mint, Some(find_nft_info(&mint).0))
+}
+
+/// Builds `verify_collection_instruction` for an NFT minted in lite mode.
+pub fn verify_collection_lite(authority: Pubkey, mint: Pubkey, collection_mint: Pubkey) -> Instruction {
+    build_verify_collection(authority, mint, collection_mint, None)
+}
+
+fn build_verify_collection(
+    authority: Pubkey,
+    mint: Pubkey,
+    collection_mint: Pubkey,
+    nft_info: Option<Pubkey>,
+) -> Instruction {
+    let accounts = accounts::VerifyCollectionMint {
+        authority,
+        metadata: find_metadata(&mint).0,
+        mint,
+        mint_authority: find_mint_authority().0,
+        nft_info,
+        collection_mint,
+        collection_info: find_collection_info(&collection_mint).0,
+        collection_metadata: find_metadata(&collection_mint).0,
+        collection_master_edition: find_master_edition(&collection_mint).0,
+        system_program: system_program::ID,
+        sysvar_instruction: sysvar::instructions::ID,
+        token_metadata_program: token_metadata_program_id(),
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::VerifyCollectionInstruction {}.data(),
+    }
+}
+
+/// Builds `unverify_collection_instruction`, run by the collection creator.
+pub fn unverify_collection(creator: Pubkey, mint: Pubkey, collection_mint: Pubkey) -> Instruction {
+    build_unverify_collection(creator, mint, collection_mint, Some(find_nft_info(&mint).0))
+}
+
+/// Builds `unverify_collection_instruction` for an NFT minted in lite mode.
+pub fn unverify_collection_lite(creator: Pubkey, mint: Pubkey, collection_mint: Pubkey) -> Instruction {
+    build_unverify_collection(creator, mint, collection_mint, None)
+}
+
+fn build_unverify_collection(
+    creator: Pubkey,
+    mint: Pubkey,
+    collection_mint: Pubkey,
+    nft_info: Option<Pubkey>,
+) -> Instruction {
+    let accounts = accounts::UnverifyCollectionMint {
+        creator,
+        metadata: find_metadata(&mint).0,
+        mint,
+        mint_authority: find_mint_authority().0,
+        nft_info,
+        collection_mint,
+        collection_info: find_collection_info(&collection_mint).0,
+        collection_metadata: find_metadata(&collection_mint).0,
+        system_program: system_program::ID,
+        sysvar_instruction: sysvar::instructions::ID,
+        token_metadata_program: token_metadata_program_id(),
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::UnverifyCollectionInstruction {}.data(),
+    }
+}
+
+/// Builds `reconcile_collection_size_instruction`, which anyone can send.
+pub fn reconcile_collection_size(collection_mint: Pubkey) -> Instruction {
+    let accounts = accounts::ReconcileCollectionSize {
+        collection_mint,
+        collection_info: find_collection_info(&collection_mint).0,
+        collection_metadata: find_metadata(&collection_mint).0,
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::ReconcileCollectionSizeInstruction {}.data(),
+    }
+}
+
+/// Builds `set_royalty_policy_instruction`, signed by the collection creator.
+pub fn set_royalty_policy(
+    creator: Pubkey,
+    collection_mint: Pubkey,
+    seller_fee_basis_points: u16,
+    creators: Vec<CreatorData>,
+    enforce: bool,
+) -> Instruction {
+    let accounts = accounts::SetRoyaltyPolicy {
+        creator,
+        collection_mint,
+        collection_info: find_collection_info(&collection_mint).0,
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::SetRoyaltyPolicyInstruction { seller_fee_basis_points, creators, enforce }.data(),
+    }
+}
+
+/// Builds `set_soulbound_policy_instruction`, signed by the collection creator before the
+/// first mint.
+pub fn set_soulbound_policy(creator: Pubkey, collection_mint: Pubkey, soulbound: bool) -> Instruction {
+    let accounts = accounts::SetSoulboundPolicy {
+        creator,
+        collection_mint,
+        collection_info: find_collection_info(&collection_mint).0,
+        event_authority: find_event_authority().0,
+        program: nft_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::SetSoulboundPolicyInstruction { soulbound }.data(),
+    }
+}
+
+/// Builds `migrate_collection_info_instruction`, rewriting a legacy `CollectionInfo`.
+pub fn migrate_collection_info(creator: Pubkey, collection_mint: Pubkey, next_index: u64) -> Instruction {
+    let accounts = accounts::MigrateCollectionInfo {
+        creator,
+        collection_mint,
+        collection_info: find_collection_info(&collection_mint).0,
+        system_program: system_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::MigrateCollectionInfoInstruction { next_index }.data(),
+    }
+}
+
+/// Builds `migrate_nft_info_instruction`, rewriting a legacy `NftInfo`. Signed by the holder,
+/// or by the creator of `collection_mint` when it is given.
+pub fn migrate_nft_info(authority: Pubkey, mint: Pubkey, collection_mint: Option<Pubkey>) -> Instruction {
+    let accounts = accounts::MigrateNftInfo {
+        authority,
+        mint,
+        token: collection_mint
+            .is_none()
+            .then(|| find_token_account(&authority, &mint, &token::ID)),
+        collection_info: collection_mint.map(|collection_mint| find_collection_info(&collection_mint).0),
+        nft_info: find_nft_info(&mint).0,
+        system_program: system_program::ID,
+    };
+
+    Instruction {
+        program_id: nft_program::ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction::MigrateNftInfoInstruction {}.data(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::Discriminator;
+
+    use super::*;
+
+    fn keys(ix: &Instruction) -> Vec<Pubkey> {
+        ix.accounts.iter().map(|meta| meta.pubkey).collect()
+    }
+
+    #[test]
+    fn burn_nft_passes_every_lock_record() {
+        let owner = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let ix = burn_nft(owner, mint, None, TokenMetadataOptions::default());
+
+        assert_eq!(ix.program_id, nft_program::ID);
+        assert_eq!(ix.accounts[0], AccountMeta::new(owner, true));
+        assert_eq!(ix.accounts[1].pubkey, find_token_account(&owner, &mint, &token::ID));
+        let keys = keys(&ix);
+        let locks = [
+            find_stake_record(&mint).0,
+            find_rental_agreement(&mint).0,
+            find_listing(&mint).0,
+            find_auction(&mint).0,
+            find_soulbound_record(&mint).0,
+        ];
+        let first = keys.iter().position(|key| *key == locks[0]).unwrap();
+        assert_eq!(keys[first..first + locks.len()], locks);
+        assert_eq!(keys[first + locks.len()], system_program::ID);
+    }
+
+    #[test]
+    fn omitted_optional_accounts_are_the_program_id() {
+        let owner = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let lite = burn_nft(owner, mint, None, TokenMetadataOptions { lite: true, ..Default::default() });
+        let full = burn_nft(owner, mint, None, TokenMetadataOptions::default());
+
+        // token_record, collection_metadata, nft_info and collection_info follow the edition.
+        assert_eq!(keys(&lite)[5..9], [nft_program::ID; 4]);
+        assert_eq!(keys(&full)[5..9], [nft_program::ID, nft_program::ID, find_nft_info(&mint).0, nft_program::ID]);
+    }
+
+    #[test]
+    fn event_cpi_accounts_come_last() {
+        let ix = list_nft(Pubkey::new_unique(), Pubkey::new_unique(), 1_000);
+        let keys = keys(&ix);
+
+        assert_eq!(keys[keys.len() - 2..], [find_event_authority().0, nft_program::ID]);
+        assert!(keys.contains(&token::ID));
+        assert!(!keys.contains(&token_2022::ID));
+    }
+
+    #[test]
+    fn instruction_data_starts_with_the_discriminator() {
+        let ix = list_nft(Pubkey::new_unique(), Pubkey::new_unique(), 1_000);
+
+        assert_eq!(&ix.data[..8], instruction::ListNftInstruction::DISCRIMINATOR);
+        assert_eq!(ix.data[8..], 1_000u64.to_le_bytes());
+    }
+
+    #[test]
+    fn soulbound_mint_adds_the_soulbound_record() {
+        let owner = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let collection_mint = Pubkey::new_unique();
+        let nft_data = NftData {
+            name: "A".to_string(),
+            symbol: "TNFT".to_string(),
+            uri: "https://example.com/a.json".to_string(),
+            seller_fee_basis_points: None,
+            creators: None,
+            uses: None,
+        };
+
+        let soulbound = mint_soulbound_nft(owner, mint, collection_mint, nft_data.clone());
+        let regular = mint_nft(owner, mint, collection_mint, nft_data);
+        let record = find_soulbound_record(&mint).0;
+
+        assert!(keys(&soulbound).contains(&record));
+        assert!(!keys(&regular).contains(&record));
+        assert_eq!(soulbound.accounts.len(), regular.accounts.len());
+    }
+}
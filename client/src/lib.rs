@@ -0,0 +1,41 @@
+pub use anchor_lang;
+pub use anchor_spl;
+pub use nft_program::{
+    self,
+    Auction,
+    AuctionKind,
+    AuctionParams,
+    Bid,
+    CollectionData,
+    CollectionInfo,
+    CreatorData,
+    ItemPool,
+    Listing,
+    MintTemplate,
+    MintTemplateData,
+    NftData,
+    NftInfo,
+    PoolItem,
+    RentalAgreement,
+    SoulboundRecord,
+    StakeRecord,
+    StakingPool,
+    UseAuthority,
+    UseMethodData,
+    UsesData,
+    validation,
+    CollectionStandard,
+    ID as PROGRAM_ID,
+    ACCOUNT_COMPRESSION_ID,
+    BUBBLEGUM_ID,
+    MPL_CORE_ID,
+    NOOP_ID,
+};
+
+pub mod accounts;
+pub mod instructions;
+pub mod pda;
+
+pub use accounts::*;
+pub use instructions::*;
+pub use pda::*;
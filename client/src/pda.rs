@@ -0,0 +1,175 @@
+use anchor_lang::{prelude::Pubkey, solana_program::pubkey};
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    metadata::mpl_token_metadata::{self, accounts::{MasterEdition, Metadata, TokenRecord}},
+};
+
+pub const AUTHORITY_SEED: &[u8] = b"authority";
+pub const COLLECTION_SEED: &[u8] = b"collection";
+pub const NFT_SEED: &[u8] = b"nft";
+pub const EVENT_AUTHORITY_SEED: &[u8] = b"__event_authority";
+pub const STAKING_POOL_SEED: &[u8] = b"staking_pool";
+pub const STAKE_SEED: &[u8] = b"stake";
+pub const RENTAL_SEED: &[u8] = b"rental";
+pub const LISTING_SEED: &[u8] = b"listing";
+pub const BID_SEED: &[u8] = b"bid";
+pub const AUCTION_SEED: &[u8] = b"auction";
+pub const AUCTION_ESCROW_SEED: &[u8] = b"auction_escrow";
+pub const BID_ESCROW_SEED: &[u8] = b"bid_escrow";
+pub const ITEM_POOL_SEED: &[u8] = b"item_pool";
+pub const MINT_TEMPLATE_SEED: &[u8] = b"mint_template";
+pub const SOULBOUND_SEED: &[u8] = b"soulbound";
+
+/// Token Auth Rules program, owner of the rule sets that pNFTs are minted with.
+pub const TOKEN_AUTH_RULES_PROGRAM_ID: Pubkey = pubkey!("auth9SigNpDKz4sJJ1DfCTuZrZNSAgh9sFD3rboVmgg");
+
+pub fn find_mint_authority() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[AUTHORITY_SEED], &nft_program::ID)
+}
+
+pub fn find_collection_info(collection_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[COLLECTION_SEED, collection_mint.as_ref()], &nft_program::ID)
+}
+
+pub fn find_nft_info(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[NFT_SEED, mint.as_ref()], &nft_program::ID)
+}
+
+pub fn find_staking_pool(collection_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[STAKING_POOL_SEED, collection_mint.as_ref()], &nft_program::ID)
+}
+
+pub fn find_stake_record(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[STAKE_SEED, mint.as_ref()], &nft_program::ID)
+}
+
+pub fn find_rental_agreement(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[RENTAL_SEED, mint.as_ref()], &nft_program::ID)
+}
+
+/// Item pool `mint_from_pool` draws the NFTs of `collection_mint` from.
+pub fn find_item_pool(collection_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ITEM_POOL_SEED, collection_mint.as_ref()], &nft_program::ID)
+}
+
+/// Template `mint_next` renders the NFTs of `collection_mint` from.
+pub fn find_mint_template(collection_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MINT_TEMPLATE_SEED, collection_mint.as_ref()], &nft_program::ID)
+}
+
+pub fn find_soulbound_record(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SOULBOUND_SEED, mint.as_ref()], &nft_program::ID)
+}
+
+pub fn find_listing(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[LISTING_SEED, mint.as_ref()], &nft_program::ID)
+}
+
+/// Bid of `bidder` on `target`, an NFT mint or a collection mint for collection-wide bids.
+pub fn find_bid(target: &Pubkey, bidder: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BID_SEED, target.as_ref(), bidder.as_ref()], &nft_program::ID)
+}
+
+/// System account holding the lamports of `bid`.
+pub fn find_bid_escrow(bid: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BID_ESCROW_SEED, bid.as_ref()], &nft_program::ID)
+}
+
+pub fn find_auction(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[AUCTION_SEED, mint.as_ref()], &nft_program::ID)
+}
+
+/// System account holding the top bid of `auction`.
+pub fn find_auction_escrow(auction: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[AUCTION_ESCROW_SEED, auction.as_ref()], &nft_program::ID)
+}
+
+pub fn find_event_authority() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[EVENT_AUTHORITY_SEED], &nft_program::ID)
+}
+
+/// Bubblegum tree config of `merkle_tree`.
+pub fn find_tree_authority(merkle_tree: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[merkle_tree.as_ref()], &nft_program::BUBBLEGUM_ID)
+}
+
+/// Bubblegum PDA that signs collection verifications of compressed NFTs.
+pub fn find_bubblegum_signer() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[nft_program::BUBBLEGUM_COLLECTION_CPI_SEED], &nft_program::BUBBLEGUM_ID)
+}
+
+pub fn find_metadata(mint: &Pubkey) -> (Pubkey, u8) {
+    Metadata::find_pda(mint)
+}
+
+pub fn find_master_edition(mint: &Pubkey) -> (Pubkey, u8) {
+    MasterEdition::find_pda(mint)
+}
+
+/// Token record of a pNFT held in `token`.
+pub fn find_token_record(mint: &Pubkey, token: &Pubkey) -> (Pubkey, u8) {
+    TokenRecord::find_pda(mint, token)
+}
+
+pub fn find_token_account(owner: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(owner, mint, token_program)
+}
+
+pub fn token_metadata_program_id() -> Pubkey {
+    mpl_token_metadata::ID
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn program_pdas_use_their_seeds() {
+        let mint = Pubkey::new_unique();
+        let (nft_info, bump) = find_nft_info(&mint);
+        assert_eq!(
+            Pubkey::create_program_address(&[b"nft", mint.as_ref(), &[bump]], &nft_program::ID).unwrap(),
+            nft_info,
+        );
+
+        let (collection_info, bump) = find_collection_info(&mint);
+        assert_eq!(
+            Pubkey::create_program_address(&[b"collection", mint.as_ref(), &[bump]], &nft_program::ID).unwrap(),
+            collection_info,
+        );
+        assert_ne!(collection_info, nft_info);
+    }
+
+    #[test]
+    fn escrows_derive_from_their_account() {
+        let mint = Pubkey::new_unique();
+        let bidder = Pubkey::new_unique();
+        let auction = find_auction(&mint).0;
+        let bid = find_bid(&mint, &bidder).0;
+
+        assert_eq!(
+            find_auction_escrow(&auction).0,
+            Pubkey::find_program_address(&[b"auction_escrow", auction.as_ref()], &nft_program::ID).0,
+        );
+        assert_eq!(
+            find_bid_escrow(&bid).0,
+            Pubkey::find_program_address(&[b"bid_escrow", bid.as_ref()], &nft_program::ID).0,
+        );
+        assert_ne!(find_bid(&mint, &Pubkey::new_unique()).0, bid);
+    }
+
+    #[test]
+    fn metaplex_pdas_match_token_metadata() {
+        let mint = Pubkey::new_unique();
+        let program = token_metadata_program_id();
+
+        assert_eq!(
+            find_metadata(&mint).0,
+            Pubkey::find_program_address(&[b"metadata", program.as_ref(), mint.as_ref()], &program).0,
+        );
+        assert_eq!(
+            find_master_edition(&mint).0,
+            Pubkey::find_program_address(&[b"metadata", program.as_ref(), mint.as_ref(), b"edition"], &program).0,
+        );
+    }
+}
@@ -18,4 +18,126 @@ pub enum NftError {
     AlreadyVerified,
     #[msg("Collection mint does not exist or does not match NFT's collection")]
     InvalidCollectionMint,
-}
\ No newline at end of file
+    #[msg("Metadata account does not belong to the NFT mint")]
+    MetadataMintMismatch,
+    #[msg("Operation is not supported for this collection standard")]
+    UnsupportedCollectionStandard,
+    #[msg("Collection max size must be greater than 0")]
+    InvalidMaxSize,
+    #[msg("Numerical overflow")]
+    NumericalOverflow,
+    #[msg("Account already uses the current layout")]
+    AccountAlreadyMigrated,
+    #[msg("Account is not a legacy account of the expected type")]
+    InvalidLegacyAccount,
+    #[msg("Signer is not allowed to perform this action")]
+    Unauthorized,
+    #[msg("Merkle tree account is not a Bubblegum tree of this collection")]
+    InvalidMerkleTree,
+    #[msg("Collection already has a Merkle tree")]
+    MerkleTreeAlreadyCreated,
+    #[msg("Master edition account is required for Token Metadata NFTs")]
+    MissingMasterEdition,
+    #[msg("NFT is already frozen")]
+    AlreadyFrozen,
+    #[msg("NFT is not frozen")]
+    NotFrozen,
+    #[msg("Reward rate must be greater than 0")]
+    InvalidRewardRate,
+    #[msg("Reward vault is required when the pool cannot mint rewards")]
+    MissingRewardVault,
+    #[msg("NFT is not verified in its collection")]
+    NftNotVerified,
+    #[msg("NFT is staked, unstake it instead")]
+    NftStaked,
+    #[msg("Rental duration must be greater than 0")]
+    InvalidRentalDuration,
+    #[msg("Rental has not expired yet")]
+    RentalNotExpired,
+    #[msg("NFT is rented out, it is returned through `reclaim`")]
+    NftRented,
+    #[msg("Listing price must be greater than 0")]
+    InvalidPrice,
+    #[msg("Price does not match the listing")]
+    PriceMismatch,
+    #[msg("Creator accounts do not match the metadata creators")]
+    CreatorMismatch,
+    #[msg("NFT is listed for sale, cancel the listing instead")]
+    NftListed,
+    #[msg("NFT does not match the bid")]
+    BidTargetMismatch,
+    #[msg("Auction times or prices are invalid")]
+    InvalidAuctionParams,
+    #[msg("Auction is not accepting bids")]
+    AuctionNotActive,
+    #[msg("Bid is below the minimum accepted bid")]
+    BidTooLow,
+    #[msg("Auction has not ended yet")]
+    AuctionNotEnded,
+    #[msg("NFT is being auctioned, settle the auction instead")]
+    NftInAuction,
+    #[msg("Minting already started in this collection")]
+    MintingStarted,
+    #[msg("Collection already has a reveal configured")]
+    RevealAlreadyConfigured,
+    #[msg("Collection has no reveal configured")]
+    RevealNotConfigured,
+    #[msg("NFT info account is required while the reveal is pending")]
+    MissingNftInfo,
+    #[msg("NFT has no index to reveal")]
+    MissingNftIndex,
+    #[msg("Reveal accounts must be NftInfo and metadata pairs")]
+    InvalidRevealAccounts,
+    #[msg("Collection already committed to a different provenance hash")]
+    ProvenanceAlreadySet,
+    #[msg("Item pool capacity must be between 1 and 10000")]
+    InvalidPoolCapacity,
+    #[msg("Item pool cannot hold these items")]
+    PoolFull,
+    #[msg("Item pool is not fully loaded yet")]
+    PoolNotLoaded,
+    #[msg("Every item of the pool has been minted")]
+    PoolEmpty,
+    #[msg("Item pool data is malformed")]
+    InvalidPoolItem,
+    #[msg("Collection metadata has no size")]
+    UnsizedCollection,
+    #[msg("Verified NFTs need the collection info and metadata to keep the collection size in sync")]
+    MissingCollectionAccounts,
+    #[msg("Parent collection accounts are missing or do not match")]
+    InvalidParentCollection,
+    #[msg("Item royalties do not match the collection's royalty policy")]
+    RoyaltyPolicyViolation,
+    #[msg("Creators are required while the collection has no default creators")]
+    MissingRoyalties,
+    #[msg("Uses need a total above zero, and exactly one use for the Single method")]
+    InvalidUses,
+    #[msg("Uses are only supported by mint_nft")]
+    UsesNotSupported,
+    #[msg("NFT has no uses left")]
+    NoUsesRemaining,
+    #[msg("NFT is soulbound")]
+    NftSoulbound,
+    #[msg("Random draws must be the last instruction of their transaction and cannot be called through CPI")]
+    DrawNotIsolated,
+    #[msg("Reveal has started, the collection supply is frozen")]
+    RevealStarted,
+    #[msg("Collection has no NFTs to reveal")]
+    NothingToReveal,
+    #[msg("Base URI differs from the one pinned by the first reveal batch")]
+    BaseUriMismatch,
+    #[msg("NFT is already revealed")]
+    NftAlreadyRevealed,
+    #[msg("The last use of a Burn NFT must be signed by its holder")]
+    HolderMustBurn,
+    #[msg("Escrowed lamports must cover the escrow's rent-exempt minimum")]
+    EscrowBelowRentExempt,
+    #[msg("Slot hashes sysvar is required to commit a provenance hash")]
+    MissingSlotHashes,
+    #[msg("NFT was not minted by this program")]
+    ForeignNft,
+    #[msg("Compressed NFTs cannot be minted into a collection with a delayed reveal")]
+    RevealUnsupported,
+    #[msg("Items of this collection must be minted soulbound")]
+    SoulboundRequired,
+}
@@ -8,6 +8,10 @@ pub struct CollectionCreated {
     pub uri: String,
     pub creator: Pubkey,
     pub created_at: i64,
+    pub provenance_hash: Option<[u8; 32]>,
+    pub starting_offset: Option<u64>,
+    /// Collection this one was verified into, `None` for a top-level collection.
+    pub parent: Option<Pubkey>,
 }
 
 #[event]
@@ -28,3 +32,311 @@ pub struct CollectionVerified {
     pub authority: Pubkey,
     pub verified_at: i64,
 }
+
+#[event]
+pub struct CollectionUnverified {
+    pub nft_mint: Pubkey,
+    pub collection_mint: Pubkey,
+    pub authority: Pubkey,
+    pub unverified_at: i64,
+}
+
+#[event]
+pub struct NftTransferred {
+    pub mint: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub transferred_at: i64,
+}
+
+#[event]
+pub struct NftBurned {
+    pub mint: Pubkey,
+    pub collection_mint: Option<Pubkey>,
+    pub owner: Pubkey,
+    pub burned_at: i64,
+}
+
+#[event]
+pub struct CompressedTreeCreated {
+    pub collection_mint: Pubkey,
+    pub merkle_tree: Pubkey,
+    pub max_depth: u32,
+    pub max_buffer_size: u32,
+    pub created_at: i64,
+}
+
+#[event]
+pub struct CompressedNftMinted {
+    pub merkle_tree: Pubkey,
+    pub leaf_index: u64,
+    pub collection_mint: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub owner: Pubkey,
+    pub minted_at: i64,
+}
+
+#[event]
+pub struct NftFrozen {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub frozen_at: i64,
+}
+
+#[event]
+pub struct NftThawed {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub frozen_since: Option<i64>,
+    pub thawed_at: i64,
+}
+
+#[event]
+pub struct StakingPoolCreated {
+    pub pool: Pubkey,
+    pub collection_mint: Pubkey,
+    pub reward_mint: Pubkey,
+    pub reward_rate: u64,
+    pub authority: Pubkey,
+    pub created_at: i64,
+}
+
+#[event]
+pub struct NftStaked {
+    pub pool: Pubkey,
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub staked_at: i64,
+}
+
+#[event]
+pub struct NftUnstaked {
+    pub pool: Pubkey,
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub staked_at: i64,
+    pub unstaked_at: i64,
+}
+
+#[event]
+pub struct RewardsClaimed {
+    pub pool: Pubkey,
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub claimed_at: i64,
+}
+
+#[event]
+pub struct NftRentedOut {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub renter: Pubkey,
+    pub fee: u64,
+    pub started_at: i64,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct NftRentalReclaimed {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub renter: Pubkey,
+    pub reclaimed_by: Pubkey,
+    pub reclaimed_at: i64,
+}
+
+#[event]
+pub struct NftListed {
+    pub mint: Pubkey,
+    pub seller: Pubkey,
+    pub price: u64,
+    pub listed_at: i64,
+}
+
+#[event]
+pub struct ListingCanceled {
+    pub mint: Pubkey,
+    pub seller: Pubkey,
+    pub canceled_at: i64,
+}
+
+#[event]
+pub struct NftSold {
+    pub mint: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub price: u64,
+    /// Part of `price` paid to the creators.
+    pub royalties: u64,
+    pub sold_at: i64,
+}
+
+#[event]
+pub struct BidPlaced {
+    pub bid: Pubkey,
+    pub bidder: Pubkey,
+    pub target: Pubkey,
+    pub collection_wide: bool,
+    pub price: u64,
+    pub placed_at: i64,
+}
+
+#[event]
+pub struct BidCanceled {
+    pub bid: Pubkey,
+    pub bidder: Pubkey,
+    pub canceled_at: i64,
+}
+
+#[event]
+pub struct BidAccepted {
+    pub bid: Pubkey,
+    pub mint: Pubkey,
+    pub seller: Pubkey,
+    pub bidder: Pubkey,
+    pub price: u64,
+    /// Part of `price` paid to the creators.
+    pub royalties: u64,
+    pub accepted_at: i64,
+}
+
+#[event]
+pub struct AuctionCreated {
+    pub auction: Pubkey,
+    pub mint: Pubkey,
+    pub seller: Pubkey,
+    pub kind: AuctionKind,
+    pub reserve_price: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+}
+
+#[event]
+pub struct AuctionBidPlaced {
+    pub auction: Pubkey,
+    pub bidder: Pubkey,
+    pub amount: u64,
+    /// End time after any late-bid extension.
+    pub end_time: i64,
+    pub placed_at: i64,
+}
+
+#[event]
+pub struct AuctionSettled {
+    pub auction: Pubkey,
+    pub mint: Pubkey,
+    pub seller: Pubkey,
+    /// `None` when the auction ended without bids and the NFT stayed with the seller.
+    pub winner: Option<Pubkey>,
+    pub price: u64,
+    /// Part of `price` paid to the creators.
+    pub royalties: u64,
+    pub settled_at: i64,
+}
+
+#[event]
+pub struct RevealConfigured {
+    pub collection_mint: Pubkey,
+    pub placeholder_uri: String,
+    pub provenance_hash: [u8; 32],
+    pub starting_offset: u64,
+}
+
+#[event]
+pub struct CollectionRevealed {
+    pub collection_mint: Pubkey,
+    pub base_uri: String,
+    /// NFTs revealed by this call; large collections reveal over several calls.
+    pub revealed_count: u32,
+    pub revealed_at: i64,
+}
+
+#[event]
+pub struct ItemPoolCreated {
+    pub item_pool: Pubkey,
+    pub collection_mint: Pubkey,
+    pub capacity: u32,
+}
+
+#[event]
+pub struct PoolItemsAdded {
+    pub item_pool: Pubkey,
+    pub added: u32,
+    /// Items uploaded so far, including this chunk.
+    pub loaded: u32,
+}
+
+#[event]
+pub struct PoolItemMinted {
+    pub item_pool: Pubkey,
+    pub mint: Pubkey,
+    /// Position of the drawn item in upload order.
+    pub pool_index: u32,
+    /// Items left to mint after this one.
+    pub remaining: u32,
+}
+
+#[event]
+pub struct MintTemplateSet {
+    pub collection_mint: Pubkey,
+    pub name_prefix: String,
+    pub uri_prefix: String,
+    pub uri_suffix: String,
+}
+
+#[event]
+pub struct CollectionSizeReconciled {
+    pub collection_mint: Pubkey,
+    /// `number_of_nfts` before the correction.
+    pub previous: u64,
+    /// Size recorded by Token Metadata, now also in `number_of_nfts`.
+    pub size: u64,
+    pub reconciled_at: i64,
+}
+
+#[event]
+pub struct RoyaltyPolicySet {
+    pub collection_mint: Pubkey,
+    pub seller_fee_basis_points: u16,
+    pub creators: Vec<CreatorData>,
+    /// Whether items must now match these royalties.
+    pub enforce: bool,
+}
+
+#[event]
+pub struct SoulboundPolicySet {
+    pub collection_mint: Pubkey,
+    /// Whether items must now be minted soulbound.
+    pub soulbound: bool,
+}
+
+#[event]
+pub struct NftUsed {
+    pub mint: Pubkey,
+    /// Holder or approved use authority that consumed the use.
+    pub authority: Pubkey,
+    pub remaining: u64,
+    /// Whether this last use burned the NFT, see `UseMethodData::Burn`.
+    pub burned: bool,
+    pub used_at: i64,
+}
+
+#[event]
+pub struct UseAuthoritySet {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    /// Wallet now allowed to sign `use_nft`, `None` once revoked.
+    pub use_authority: Option<Pubkey>,
+}
+
+#[event]
+pub struct SoulboundRevoked {
+    pub mint: Pubkey,
+    pub collection_mint: Pubkey,
+    /// Holder the NFT was burned from.
+    pub owner: Pubkey,
+    pub revoked_at: i64,
+}
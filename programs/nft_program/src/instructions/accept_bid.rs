@@ -0,0 +1,156 @@
+use super::*;
+
+/// Sells an NFT to a bid. Collection-wide bids accept any NFT whose `NftInfo` is verified in the
+/// bid's collection. Royalties are paid out of the escrow exactly as in `buy_nft`, with the
+/// metadata creators passed as writable `remaining_accounts`.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptBid<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    #[account(mut)]
+    /// CHECK: Bidder recorded on the bid
+    pub bidder: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = seller,
+        associated_token::token_program = token_program,
+        constraint = seller_token.amount == 1 @ NftError::Unauthorized,
+    )]
+    pub seller_token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = seller,
+        associated_token::mint = mint,
+        associated_token::authority = bidder,
+        associated_token::token_program = token_program,
+    )]
+    pub bidder_token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub metadata: Box<Account<'info, MetadataAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"nft", mint.key().as_ref()],
+        bump = nft_info.bump,
+    )]
+    pub nft_info: Option<Account<'info, NftInfo>>,
+
+    #[account(
+        mut,
+        close = bidder,
+        seeds = [b"bid", bid.target.as_ref(), bidder.key().as_ref()],
+        bump = bid.bump,
+        has_one = bidder @ NftError::Unauthorized,
+    )]
+    pub bid: Box<Account<'info, Bid>>,
+
+    #[account(
+        mut,
+        seeds = [b"bid_escrow", bid.key().as_ref()],
+        bump = bid.escrow_bump,
+    )]
+    pub escrow: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> AcceptBid<'info> {
+    fn validate_accept(&self, price: u64) -> Result<()> {
+        if self.metadata.mint != self.mint.key() {
+            return Err(error!(NftError::MetadataMintMismatch));
+        }
+        if self.bid.price != price {
+            return Err(error!(NftError::PriceMismatch));
+        }
+
+        if !self.bid.collection_wide {
+            if self.bid.target != self.mint.key() {
+                return Err(error!(NftError::BidTargetMismatch));
+            }
+            return Ok(());
+        }
+
+        let nft_info = self.nft_info.as_ref().ok_or(NftError::BidTargetMismatch)?;
+        if nft_info.collection_mint != self.bid.target {
+            return Err(error!(NftError::BidTargetMismatch));
+        }
+        if !nft_info.verified {
+            return Err(error!(NftError::NftNotVerified));
+        }
+        Ok(())
+    }
+}
+
+pub fn accept_bid<'info>(ctx: Context<'_, '_, 'info, 'info, AcceptBid<'info>>, price: u64) -> Result<()> {
+    ctx.accounts.validate_accept(price)?;
+
+    transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.seller_token.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.bidder_token.to_account_info(),
+                authority: ctx.accounts.seller.to_account_info(),
+            },
+        ),
+        1,
+        ctx.accounts.mint.decimals,
+    )?;
+    msg!("NFT delivered to bidder!");
+
+    let bid_key = ctx.accounts.bid.key();
+    let seeds = &[&b"bid_escrow"[..], bid_key.as_ref(), &[ctx.accounts.bid.escrow_bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    let royalties = SalePaymentAccounts {
+        system_program: &ctx.accounts.system_program.to_account_info(),
+        payer: &ctx.accounts.escrow.to_account_info(),
+        seller: &ctx.accounts.seller.to_account_info(),
+        creators: ctx.remaining_accounts,
+    }
+    .pay(&ctx.accounts.metadata, price, signer_seeds)?;
+    msg!("Sale paid, {} lamports of royalties!", royalties);
+
+    // Anything sent to the escrow on top of the bid goes back to the bidder.
+    let leftover = ctx.accounts.escrow.lamports();
+    if leftover > 0 {
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.escrow.to_account_info(),
+                    to: ctx.accounts.bidder.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            leftover,
+        )?;
+    }
+
+    if let Some(nft_info) = ctx.accounts.nft_info.as_mut() {
+        nft_info.owner = ctx.accounts.bidder.key();
+    }
+
+    emit_cpi!(BidAccepted {
+        bid: bid_key,
+        mint: ctx.accounts.mint.key(),
+        seller: ctx.accounts.seller.key(),
+        bidder: ctx.accounts.bidder.key(),
+        price,
+        royalties,
+        accepted_at: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
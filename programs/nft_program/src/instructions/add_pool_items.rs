@@ -0,0 +1,63 @@
+use super::*;
+
+/// Appends a chunk of `items` to an `ItemPool`, growing the account to fit them.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddPoolItems<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"item_pool", item_pool.collection_mint.as_ref()],
+        bump = item_pool.bump,
+        has_one = creator @ NftError::Unauthorized,
+    )]
+    pub item_pool: Box<Account<'info, ItemPool>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddPoolItems<'info> {
+    fn validate_items(&self, items: &[PoolItem]) -> Result<u32> {
+        let loaded = u32::try_from(items.len())
+            .ok()
+            .and_then(|count| self.item_pool.loaded.checked_add(count))
+            .filter(|loaded| *loaded <= self.item_pool.capacity)
+            .ok_or(NftError::PoolFull)?;
+
+        for item in items {
+            validation::validate_name(&item.name)?;
+            validation::validate_uri(&item.uri)?;
+        }
+        Ok(loaded)
+    }
+}
+
+pub fn add_pool_items(ctx: Context<AddPoolItems>, items: Vec<PoolItem>) -> Result<()> {
+    let loaded = ctx.accounts.validate_items(&items)?;
+
+    let account_info = ctx.accounts.item_pool.to_account_info();
+    resize_with_rent(
+        &account_info,
+        &ctx.accounts.creator.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ItemPool::space(ctx.accounts.item_pool.capacity, loaded),
+    )?;
+
+    {
+        let mut data = account_info.try_borrow_mut_data()?;
+        for (index, item) in (ctx.accounts.item_pool.loaded..).zip(&items) {
+            ctx.accounts.item_pool.write_item(&mut data, index, item)?;
+        }
+    }
+    ctx.accounts.item_pool.loaded = loaded;
+
+    emit_cpi!(PoolItemsAdded {
+        item_pool: ctx.accounts.item_pool.key(),
+        added: items.len() as u32,
+        loaded,
+    });
+
+    Ok(())
+}
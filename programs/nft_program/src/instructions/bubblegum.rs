@@ -0,0 +1,248 @@
+use super::*;
+use crate::instructions::shared::validation::ValidatableData;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
+
+/// Metaplex Bubblegum program.
+pub const BUBBLEGUM_ID: Pubkey = pubkey!("BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY");
+/// SPL Account Compression program, owner of the Merkle tree accounts.
+pub const ACCOUNT_COMPRESSION_ID: Pubkey = pubkey!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+/// SPL Noop program, used by Bubblegum to log leaves.
+pub const NOOP_ID: Pubkey = pubkey!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+
+/// Seed of Bubblegum's PDA that signs collection verifications.
+pub const BUBBLEGUM_COLLECTION_CPI_SEED: &[u8] = b"collection_cpi";
+
+const CREATE_TREE_DISCRIMINATOR: [u8; 8] = [165, 83, 136, 142, 89, 202, 47, 220];
+const MINT_TO_COLLECTION_V1_DISCRIMINATOR: [u8; 8] = [153, 18, 178, 47, 197, 158, 86, 15];
+const TREE_CONFIG_DISCRIMINATOR: [u8; 8] = [122, 245, 175, 248, 171, 34, 0, 207];
+
+/// Offset of `num_minted` in Bubblegum's `TreeConfig`: discriminator, tree creator, tree delegate
+/// and total mint capacity come first.
+const TREE_CONFIG_NUM_MINTED_OFFSET: usize = 8 + 32 + 32 + 8;
+
+// Borsh mirrors of the Bubblegum instruction arguments this program sends. Only the variants
+// used here are declared; their order must match Bubblegum's enums.
+
+#[derive(AnchorSerialize)]
+enum BubblegumTokenStandard {
+    NonFungible,
+}
+
+#[derive(AnchorSerialize)]
+enum BubblegumTokenProgramVersion {
+    Original,
+}
+
+#[derive(AnchorSerialize)]
+struct BubblegumCollection {
+    verified: bool,
+    key: Pubkey,
+}
+
+// Always sent as `None`, declared for the argument layout only.
+#[allow(dead_code)]
+#[derive(AnchorSerialize)]
+struct BubblegumUses {
+    use_method: u8,
+    remaining: u64,
+    total: u64,
+}
+
+#[derive(AnchorSerialize)]
+struct BubblegumCreator {
+    address: Pubkey,
+    verified: bool,
+    share: u8,
+}
+
+#[derive(AnchorSerialize)]
+struct MetadataArgs {
+    name: String,
+    symbol: String,
+    uri: String,
+    seller_fee_basis_points: u16,
+    primary_sale_happened: bool,
+    is_mutable: bool,
+    edition_nonce: Option<u8>,
+    token_standard: Option<BubblegumTokenStandard>,
+    collection: Option<BubblegumCollection>,
+    uses: Option<BubblegumUses>,
+    token_program_version: BubblegumTokenProgramVersion,
+    creators: Vec<BubblegumCreator>,
+}
+
+#[derive(AnchorSerialize)]
+struct CreateTreeArgs {
+    max_depth: u32,
+    max_buffer_size: u32,
+    public: Option<bool>,
+}
+
+fn instruction_data<T: AnchorSerialize>(discriminator: [u8; 8], args: &T) -> Result<Vec<u8>> {
+    let mut data = discriminator.to_vec();
+    args.serialize(&mut data)?;
+    Ok(data)
+}
+
+/// Reads the number of leaves minted so far from a Bubblegum `TreeConfig`, which is the leaf
+/// index of the next compressed NFT.
+pub fn tree_num_minted(tree_authority: &AccountInfo) -> Result<u64> {
+    let data = tree_authority.try_borrow_data()?;
+    if *tree_authority.owner != BUBBLEGUM_ID
+        || data.len() < TREE_CONFIG_NUM_MINTED_OFFSET + 8
+        || data[..8] != TREE_CONFIG_DISCRIMINATOR
+    {
+        return Err(error!(NftError::InvalidMerkleTree));
+    }
+
+    let mut num_minted = [0u8; 8];
+    num_minted.copy_from_slice(&data[TREE_CONFIG_NUM_MINTED_OFFSET..TREE_CONFIG_NUM_MINTED_OFFSET + 8]);
+    Ok(u64::from_le_bytes(num_minted))
+}
+
+/// Accounts shared by the Bubblegum CPIs, with the program PDA as tree creator and delegate.
+pub struct BubblegumAccounts<'a, 'info> {
+    pub bubblegum_program: &'a AccountInfo<'info>,
+    pub tree_authority: &'a AccountInfo<'info>,
+    pub merkle_tree: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub authority: &'a AccountInfo<'info>,
+    pub log_wrapper: &'a AccountInfo<'info>,
+    pub compression_program: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+/// Collection accounts Bubblegum verifies a compressed NFT into.
+pub struct BubblegumCollectionAccounts<'a, 'info> {
+    pub collection_mint: &'a AccountInfo<'info>,
+    pub collection_metadata: &'a AccountInfo<'info>,
+    pub collection_master_edition: &'a AccountInfo<'info>,
+    pub bubblegum_signer: &'a AccountInfo<'info>,
+    pub token_metadata_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> BubblegumAccounts<'a, 'info> {
+    /// Initializes the tree config of a private tree over a Merkle tree account that was already
+    /// allocated and assigned to the account compression program.
+    pub fn create_tree(&self, signer_seeds: &[&[&[u8]]], max_depth: u32, max_buffer_size: u32) -> Result<()> {
+        let args = CreateTreeArgs {
+            max_depth,
+            max_buffer_size,
+            public: Some(false),
+        };
+
+        let instruction = Instruction {
+            program_id: BUBBLEGUM_ID,
+            accounts: vec![
+                AccountMeta::new(self.tree_authority.key(), false),
+                AccountMeta::new(self.merkle_tree.key(), false),
+                AccountMeta::new(self.payer.key(), true),
+                AccountMeta::new_readonly(self.authority.key(), true),
+                AccountMeta::new_readonly(self.log_wrapper.key(), false),
+                AccountMeta::new_readonly(self.compression_program.key(), false),
+                AccountMeta::new_readonly(self.system_program.key(), false),
+            ],
+            data: instruction_data(CREATE_TREE_DISCRIMINATOR, &args)?,
+        };
+
+        invoke_signed(
+            &instruction,
+            &[
+                self.tree_authority.clone(),
+                self.merkle_tree.clone(),
+                self.payer.clone(),
+                self.authority.clone(),
+                self.log_wrapper.clone(),
+                self.compression_program.clone(),
+                self.system_program.clone(),
+            ],
+            signer_seeds,
+        )?;
+        Ok(())
+    }
+
+    /// Appends a compressed NFT owned by `leaf_owner` to the tree, verified into `collection`
+    /// with the program PDA signing as collection update authority.
+    pub fn mint_to_collection(
+        &self,
+        collection: &BubblegumCollectionAccounts<'a, 'info>,
+        leaf_owner: &AccountInfo<'info>,
+        signer_seeds: &[&[&[u8]]],
+        nft_data: &NftData,
+    ) -> Result<()> {
+        let args = MetadataArgs {
+            name: nft_data.name.clone(),
+            symbol: nft_data.symbol.clone(),
+            uri: nft_data.uri.clone(),
+            seller_fee_basis_points: nft_data.seller_fee_basis_points(),
+            primary_sale_happened: false,
+            is_mutable: true,
+            edition_nonce: None,
+            token_standard: Some(BubblegumTokenStandard::NonFungible),
+            collection: Some(BubblegumCollection {
+                verified: false,
+                key: collection.collection_mint.key(),
+            }),
+            uses: None,
+            token_program_version: BubblegumTokenProgramVersion::Original,
+            creators: nft_data
+                .creators()
+                .iter()
+                .map(|creator| BubblegumCreator {
+                    address: creator.address,
+                    verified: creator.verified,
+                    share: creator.share,
+                })
+                .collect(),
+        };
+
+        // The absent collection authority record is passed as the Bubblegum program id.
+        let instruction = Instruction {
+            program_id: BUBBLEGUM_ID,
+            accounts: vec![
+                AccountMeta::new(self.tree_authority.key(), false),
+                AccountMeta::new_readonly(leaf_owner.key(), false),
+                AccountMeta::new_readonly(leaf_owner.key(), false),
+                AccountMeta::new(self.merkle_tree.key(), false),
+                AccountMeta::new(self.payer.key(), true),
+                AccountMeta::new_readonly(self.authority.key(), true),
+                AccountMeta::new_readonly(self.authority.key(), true),
+                AccountMeta::new_readonly(BUBBLEGUM_ID, false),
+                AccountMeta::new_readonly(collection.collection_mint.key(), false),
+                AccountMeta::new(collection.collection_metadata.key(), false),
+                AccountMeta::new_readonly(collection.collection_master_edition.key(), false),
+                AccountMeta::new_readonly(collection.bubblegum_signer.key(), false),
+                AccountMeta::new_readonly(self.log_wrapper.key(), false),
+                AccountMeta::new_readonly(self.compression_program.key(), false),
+                AccountMeta::new_readonly(collection.token_metadata_program.key(), false),
+                AccountMeta::new_readonly(self.system_program.key(), false),
+            ],
+            data: instruction_data(MINT_TO_COLLECTION_V1_DISCRIMINATOR, &args)?,
+        };
+
+        invoke_signed(
+            &instruction,
+            &[
+                self.tree_authority.clone(),
+                leaf_owner.clone(),
+                self.merkle_tree.clone(),
+                self.payer.clone(),
+                self.authority.clone(),
+                self.bubblegum_program.clone(),
+                collection.collection_mint.clone(),
+                collection.collection_metadata.clone(),
+                collection.collection_master_edition.clone(),
+                collection.bubblegum_signer.clone(),
+                self.log_wrapper.clone(),
+                self.compression_program.clone(),
+                collection.token_metadata_program.clone(),
+                self.system_program.clone(),
+            ],
+            signer_seeds,
+        )?;
+        Ok(())
+    }
+}
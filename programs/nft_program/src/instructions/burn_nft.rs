@@ -0,0 +1,164 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct BurnNFT<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub metadata: Box<Account<'info, MetadataAccount>>,
+
+    #[account(mut)]
+    /// CHECK: Master edition of `mint`, validated by the metaplex program
+    pub edition: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Owner token record, only passed for pNFTs
+    pub token_record: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    /// CHECK: Metadata of the collection, validated by the metaplex program. Required with
+    /// `collection_info` for verified items so both collection sizes are decremented.
+    pub collection_metadata: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"nft", mint.key().as_ref()],
+        bump = nft_info.bump,
+    )]
+    pub nft_info: Option<Account<'info, NftInfo>>,
+
+    #[account(
+        mut,
+        seeds = [b"collection", collection_info.mint.as_ref()],
+        bump = collection_info.bump,
+    )]
+    pub collection_info: Option<Account<'info, CollectionInfo>>,
+
+    /// CHECK: Stake record PDA of `mint`, must not exist. Checked by `NftLockAccounts`
+    pub stake_record: UncheckedAccount<'info>,
+
+    /// CHECK: Rental agreement PDA of `mint`, must not exist. Checked by `NftLockAccounts`
+    pub rental_agreement: UncheckedAccount<'info>,
+
+    /// CHECK: Listing PDA of `mint`, must not exist. Checked by `NftLockAccounts`
+    pub listing: UncheckedAccount<'info>,
+
+    /// CHECK: Auction PDA of `mint`, must not exist. Checked by `NftLockAccounts`
+    pub auction: UncheckedAccount<'info>,
+
+    /// CHECK: Soulbound record PDA of `mint`, must not exist. Checked by `NftLockAccounts`
+    pub soulbound_record: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(address = INSTRUCTIONS_ID)]
+    /// CHECK: Sysvar instruction account that is being checked with an address constraint
+    pub sysvar_instruction: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub token_metadata_program: Program<'info, Metadata>,
+}
+
+impl<'info> BurnNFT<'info> {
+    fn validate_burn(&self) -> Result<()> {
+        if self.metadata.mint != self.mint.key() {
+            return Err(error!(NftError::MetadataMintMismatch));
+        }
+        self.require_unlocked()?;
+
+        if let Some(collection_info) = &self.collection_info {
+            if self.collection_mint() != Some(collection_info.mint) {
+                return Err(error!(NftError::InvalidCollectionMint));
+            }
+        }
+
+        if self.is_verified() && (self.collection_info.is_none() || self.collection_metadata.is_none()) {
+            return Err(error!(NftError::MissingCollectionAccounts));
+        }
+        Ok(())
+    }
+
+    fn require_unlocked(&self) -> Result<()> {
+        NftLockAccounts {
+            stake_record: &self.stake_record.to_account_info(),
+            rental_agreement: &self.rental_agreement.to_account_info(),
+            listing: &self.listing.to_account_info(),
+            auction: &self.auction.to_account_info(),
+            soulbound_record: &self.soulbound_record.to_account_info(),
+        }
+        .require_unlocked(&self.mint.key())
+    }
+
+    fn collection_mint(&self) -> Option<Pubkey> {
+        self.metadata.collection.as_ref().map(|collection| collection.key)
+    }
+
+    fn is_verified(&self) -> bool {
+        self.metadata
+            .collection
+            .as_ref()
+            .is_some_and(|collection| collection.verified)
+    }
+}
+
+pub fn burn_nft(ctx: Context<BurnNFT>) -> Result<()> {
+    ctx.accounts.validate_burn()?;
+    let was_verified = ctx.accounts.is_verified();
+    let collection_mint = ctx.accounts.collection_mint();
+
+    let clock = Clock::get()?;
+
+    let token_record = ctx.accounts.token_record.as_ref().map(|record| record.to_account_info());
+    let collection_metadata = ctx
+        .accounts
+        .collection_metadata
+        .as_ref()
+        .map(|metadata| metadata.to_account_info());
+
+    MetadataBurnAccounts {
+        token_metadata_program: &ctx.accounts.token_metadata_program.to_account_info(),
+        authority: &ctx.accounts.owner.to_account_info(),
+        collection_metadata: collection_metadata.as_ref(),
+        metadata: &ctx.accounts.metadata.to_account_info(),
+        edition: &ctx.accounts.edition.to_account_info(),
+        mint: &ctx.accounts.mint.to_account_info(),
+        token: &ctx.accounts.token.to_account_info(),
+        token_record: token_record.as_ref(),
+        system_program: &ctx.accounts.system_program.to_account_info(),
+        sysvar_instructions: &ctx.accounts.sysvar_instruction.to_account_info(),
+        spl_token_program: &ctx.accounts.token_program.to_account_info(),
+    }
+    .burn(&[])?;
+    msg!("NFT burned!");
+
+    // Token Metadata decremented the collection's sized `CollectionDetails` during the burn.
+    if was_verified {
+        if let Some(collection_info) = ctx.accounts.collection_info.as_mut() {
+            collection_info.number_of_nfts = collection_info.number_of_nfts.saturating_sub(1);
+            msg!("Updated collection count to: {}", collection_info.number_of_nfts);
+        }
+    }
+
+    emit_cpi!(NftBurned {
+        mint: ctx.accounts.mint.key(),
+        collection_mint,
+        owner: ctx.accounts.owner.key(),
+        burned_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
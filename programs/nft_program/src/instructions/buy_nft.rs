@@ -0,0 +1,125 @@
+use super::*;
+
+/// Buys a listed NFT. `price` must match the listing so the buyer never pays more than they
+/// saw. Royalties go to the metadata creators, passed as writable `remaining_accounts` in
+/// metadata order, and the seller receives the rest.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct BuyNFT<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(mut)]
+    /// CHECK: Seller recorded on the listing
+    pub seller: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = seller,
+        associated_token::token_program = token_program,
+    )]
+    pub seller_token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::mint = mint,
+        associated_token::authority = buyer,
+        associated_token::token_program = token_program,
+    )]
+    pub buyer_token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub metadata: Box<Account<'info, MetadataAccount>>,
+
+    /// CHECK: Master edition of `mint`, validated by the metaplex program
+    pub edition: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        seeds = [b"authority"],
+        bump,
+    )]
+    /// CHECK: This account is used for signing purposes only
+    pub mint_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"nft", mint.key().as_ref()],
+        bump = nft_info.bump,
+    )]
+    pub nft_info: Option<Account<'info, NftInfo>>,
+
+    #[account(
+        mut,
+        close = seller,
+        seeds = [b"listing", mint.key().as_ref()],
+        bump = listing.bump,
+        has_one = seller @ NftError::Unauthorized,
+    )]
+    pub listing: Box<Account<'info, Listing>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
+}
+
+impl<'info> BuyNFT<'info> {
+    fn validate_purchase(&self, price: u64) -> Result<()> {
+        if self.metadata.mint != self.mint.key() {
+            return Err(error!(NftError::MetadataMintMismatch));
+        }
+        if self.listing.price != price {
+            return Err(error!(NftError::PriceMismatch));
+        }
+        Ok(())
+    }
+}
+
+pub fn buy_nft<'info>(ctx: Context<'_, '_, 'info, 'info, BuyNFT<'info>>, price: u64) -> Result<()> {
+    ctx.accounts.validate_purchase(price)?;
+
+    let royalties = SalePaymentAccounts {
+        system_program: &ctx.accounts.system_program.to_account_info(),
+        payer: &ctx.accounts.buyer.to_account_info(),
+        seller: &ctx.accounts.seller.to_account_info(),
+        creators: ctx.remaining_accounts,
+    }
+    .pay(&ctx.accounts.metadata, price, &[])?;
+    msg!("Sale paid, {} lamports of royalties!", royalties);
+
+    let authority_bump = ctx.bumps.mint_authority;
+    let seeds = &[&b"authority"[..], &[authority_bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    let edition = ctx.accounts.edition.as_ref().map(|edition| edition.to_account_info());
+    NftFreezeAccounts {
+        token_program: &ctx.accounts.token_program.to_account_info(),
+        token_metadata_program: &ctx.accounts.token_metadata_program.to_account_info(),
+        token: &ctx.accounts.seller_token.to_account_info(),
+        mint: &ctx.accounts.mint.to_account_info(),
+        edition: edition.as_ref(),
+        owner: &ctx.accounts.seller.to_account_info(),
+        authority: &ctx.accounts.mint_authority.to_account_info(),
+    }
+    .thaw_to(&ctx.accounts.buyer_token.to_account_info(), signer_seeds)?;
+    msg!("NFT delivered to buyer!");
+
+    if let Some(nft_info) = ctx.accounts.nft_info.as_mut() {
+        nft_info.owner = ctx.accounts.buyer.key();
+    }
+
+    emit_cpi!(NftSold {
+        mint: ctx.accounts.mint.key(),
+        seller: ctx.accounts.seller.key(),
+        buyer: ctx.accounts.buyer.key(),
+        price,
+        royalties,
+        sold_at: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelBid<'info> {
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    #[account(
+        mut,
+        close = bidder,
+        seeds = [b"bid", bid.target.as_ref(), bidder.key().as_ref()],
+        bump = bid.bump,
+        has_one = bidder @ NftError::Unauthorized,
+    )]
+    pub bid: Box<Account<'info, Bid>>,
+
+    #[account(
+        mut,
+        seeds = [b"bid_escrow", bid.key().as_ref()],
+        bump = bid.escrow_bump,
+    )]
+    pub escrow: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn cancel_bid(ctx: Context<CancelBid>) -> Result<()> {
+    let bid_key = ctx.accounts.bid.key();
+    let seeds = &[&b"bid_escrow"[..], bid_key.as_ref(), &[ctx.accounts.bid.escrow_bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.escrow.to_account_info(),
+                to: ctx.accounts.bidder.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        ctx.accounts.escrow.lamports(),
+    )?;
+    msg!("Bid refunded!");
+
+    emit_cpi!(BidCanceled {
+        bid: bid_key,
+        bidder: ctx.accounts.bidder.key(),
+        canceled_at: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
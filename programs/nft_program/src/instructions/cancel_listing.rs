@@ -0,0 +1,68 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelListing<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = seller,
+        associated_token::token_program = token_program,
+    )]
+    pub token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Master edition of `mint`, validated by the metaplex program
+    pub edition: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        seeds = [b"authority"],
+        bump,
+    )]
+    /// CHECK: This account is used for signing purposes only
+    pub mint_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = seller,
+        seeds = [b"listing", mint.key().as_ref()],
+        bump = listing.bump,
+        has_one = seller @ NftError::Unauthorized,
+    )]
+    pub listing: Box<Account<'info, Listing>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub token_metadata_program: Program<'info, Metadata>,
+}
+
+pub fn cancel_listing(ctx: Context<CancelListing>) -> Result<()> {
+    let authority_bump = ctx.bumps.mint_authority;
+    let seeds = &[&b"authority"[..], &[authority_bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    let edition = ctx.accounts.edition.as_ref().map(|edition| edition.to_account_info());
+    NftFreezeAccounts {
+        token_program: &ctx.accounts.token_program.to_account_info(),
+        token_metadata_program: &ctx.accounts.token_metadata_program.to_account_info(),
+        token: &ctx.accounts.token.to_account_info(),
+        mint: &ctx.accounts.mint.to_account_info(),
+        edition: edition.as_ref(),
+        owner: &ctx.accounts.seller.to_account_info(),
+        authority: &ctx.accounts.mint_authority.to_account_info(),
+    }
+    .thaw(signer_seeds)?;
+    msg!("Listing canceled!");
+
+    emit_cpi!(ListingCanceled {
+        mint: ctx.accounts.mint.key(),
+        seller: ctx.accounts.seller.key(),
+        canceled_at: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use super::*;
+
+/// Pays out the rewards a staked NFT accrued so far, keeping it staked.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimRewards<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"staking_pool", staking_pool.collection_mint.as_ref()],
+        bump = staking_pool.bump,
+    )]
+    pub staking_pool: Box<Account<'info, StakingPool>>,
+
+    #[account(
+        mut,
+        seeds = [b"stake", stake_record.mint.as_ref()],
+        bump = stake_record.bump,
+        has_one = owner @ NftError::Unauthorized,
+        constraint = stake_record.pool == staking_pool.key() @ NftError::Unauthorized,
+    )]
+    pub stake_record: Box<Account<'info, StakeRecord>>,
+
+    #[account(
+        mut,
+        address = staking_pool.reward_mint,
+        mint::token_program = reward_token_program,
+    )]
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Omit when the pool mints rewards itself.
+    #[account(
+        mut,
+        associated_token::mint = reward_mint,
+        associated_token::authority = staking_pool,
+        associated_token::token_program = reward_token_program,
+    )]
+    pub reward_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = reward_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = reward_token_program,
+    )]
+    pub reward_destination: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub reward_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let stake_record = &mut ctx.accounts.stake_record;
+    stake_record.accrue(ctx.accounts.staking_pool.reward_rate, clock.unix_timestamp)?;
+    let amount = std::mem::take(&mut stake_record.accrued_points);
+
+    let collection_mint = ctx.accounts.staking_pool.collection_mint;
+    let seeds = &[b"staking_pool", collection_mint.as_ref(), &[ctx.accounts.staking_pool.bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    let reward_vault = ctx.accounts.reward_vault.as_ref().map(|vault| vault.to_account_info());
+    RewardPayoutAccounts {
+        token_program: &ctx.accounts.reward_token_program.to_account_info(),
+        reward_mint: &ctx.accounts.reward_mint.to_account_info(),
+        reward_vault: reward_vault.as_ref(),
+        destination: &ctx.accounts.reward_destination.to_account_info(),
+        pool: &ctx.accounts.staking_pool.to_account_info(),
+    }
+    .pay(amount, signer_seeds)?;
+    msg!("Claimed {} reward tokens", amount);
+
+    emit_cpi!(RewardsClaimed {
+        pool: ctx.accounts.staking_pool.key(),
+        mint: ctx.accounts.stake_record.mint,
+        owner: ctx.accounts.owner.key(),
+        amount,
+        claimed_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
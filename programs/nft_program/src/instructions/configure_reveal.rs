@@ -0,0 +1,70 @@
+use super::*;
+
+/// Sets up a delayed reveal before the first `mint_nft`: items get `placeholder_uri` until the
+/// creator runs `reveal`, and `provenance_hash` commits to the final metadata up front. A hash
+/// already committed by `create_collection` must be passed again unchanged.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(placeholder_uri: String)]
+pub struct ConfigureReveal<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub collection_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"collection", collection_mint.key().as_ref()],
+        bump = collection_info.bump,
+        has_one = creator @ NftError::Unauthorized,
+        realloc = CollectionInfo::space(&collection_info.name, &collection_info.symbol, &collection_info.uri)
+            + placeholder_uri.len(),
+        realloc::payer = creator,
+        realloc::zero = false,
+    )]
+    pub collection_info: Box<Account<'info, CollectionInfo>>,
+
+    #[account(address = SLOT_HASHES_ID)]
+    /// CHECK: Slot hashes sysvar, seeds the starting offset
+    pub slot_hashes: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ConfigureReveal<'info> {
+    fn validate_reveal_config(&self, placeholder_uri: &str) -> Result<()> {
+        if self.collection_info.standard != CollectionStandard::Metaplex {
+            return Err(error!(NftError::UnsupportedCollectionStandard));
+        }
+        if self.collection_info.next_index > 0 {
+            return Err(error!(NftError::MintingStarted));
+        }
+        if !self.collection_info.placeholder_uri.is_empty() {
+            return Err(error!(NftError::RevealAlreadyConfigured));
+        }
+        validation::validate_uri(placeholder_uri)
+    }
+}
+
+pub fn configure_reveal(
+    ctx: Context<ConfigureReveal>,
+    placeholder_uri: String,
+    provenance_hash: [u8; 32],
+) -> Result<()> {
+    ctx.accounts.validate_reveal_config(&placeholder_uri)?;
+
+    let slot_hash = recent_slot_hash(&ctx.accounts.slot_hashes)?;
+    let collection_info = &mut ctx.accounts.collection_info;
+    collection_info.commit_provenance(provenance_hash, slot_hash)?;
+    collection_info.placeholder_uri = placeholder_uri.clone();
+    collection_info.revealed = false;
+
+    emit_cpi!(RevealConfigured {
+        collection_mint: ctx.accounts.collection_mint.key(),
+        placeholder_uri,
+        provenance_hash,
+        starting_offset: ctx.accounts.collection_info.starting_offset.unwrap_or_default(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,132 @@
+use super::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct AuctionParams {
+    pub kind: AuctionKind,
+    /// Lowest winning price; the floor a Dutch auction decays to.
+    pub reserve_price: u64,
+    /// Minimum raise over the standing bid, English auctions only.
+    pub min_increment: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+}
+
+/// Puts an NFT up for auction. The NFT stays in the seller's wallet, frozen with the program
+/// PDA as delegate until `settle_auction`.
+/// Only SPL Token NFTs with Token Metadata can be auctioned, since `settle_auction` pays
+/// royalties from it.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateAuction<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = seller,
+        associated_token::token_program = token_program,
+        constraint = token.amount == 1 @ NftError::Unauthorized,
+    )]
+    pub token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Master edition of `mint`, validated by the metaplex program
+    pub edition: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        seeds = [b"authority"],
+        bump,
+    )]
+    /// CHECK: This account is used for signing purposes only
+    pub mint_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = seller,
+        space = Auction::SPACE,
+        seeds = [b"auction", mint.key().as_ref()],
+        bump,
+    )]
+    pub auction: Box<Account<'info, Auction>>,
+
+    #[account(
+        seeds = [b"auction_escrow", auction.key().as_ref()],
+        bump,
+    )]
+    pub escrow: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub token_metadata_program: Program<'info, Metadata>,
+}
+
+impl<'info> CreateAuction<'info> {
+    fn validate_auction(&self, params: &AuctionParams, now: i64) -> Result<()> {
+        if params.reserve_price == 0 || params.start_time >= params.end_time || params.end_time <= now {
+            return Err(error!(NftError::InvalidAuctionParams));
+        }
+        if let AuctionKind::Dutch { start_price } = params.kind {
+            if start_price <= params.reserve_price {
+                return Err(error!(NftError::InvalidAuctionParams));
+            }
+        }
+        // Every accepted bid is at least the reserve, which must keep the escrow rent exempt.
+        if params.reserve_price < Rent::get()?.minimum_balance(0) {
+            return Err(error!(NftError::EscrowBelowRentExempt));
+        }
+        if self.token.is_frozen() {
+            return Err(error!(NftError::AlreadyFrozen));
+        }
+        Ok(())
+    }
+}
+
+pub fn create_auction(ctx: Context<CreateAuction>, params: AuctionParams) -> Result<()> {
+    let clock = Clock::get()?;
+    ctx.accounts.validate_auction(&params, clock.unix_timestamp)?;
+
+    let authority_bump = ctx.bumps.mint_authority;
+    let seeds = &[&b"authority"[..], &[authority_bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    let edition = ctx.accounts.edition.as_ref().map(|edition| edition.to_account_info());
+    NftFreezeAccounts {
+        token_program: &ctx.accounts.token_program.to_account_info(),
+        token_metadata_program: &ctx.accounts.token_metadata_program.to_account_info(),
+        token: &ctx.accounts.token.to_account_info(),
+        mint: &ctx.accounts.mint.to_account_info(),
+        edition: edition.as_ref(),
+        owner: &ctx.accounts.seller.to_account_info(),
+        authority: &ctx.accounts.mint_authority.to_account_info(),
+    }
+    .freeze(signer_seeds)?;
+    msg!("NFT frozen for auction!");
+
+    let auction = &mut ctx.accounts.auction;
+    auction.mint = ctx.accounts.mint.key();
+    auction.seller = ctx.accounts.seller.key();
+    auction.kind = params.kind;
+    auction.reserve_price = params.reserve_price;
+    auction.min_increment = params.min_increment;
+    auction.start_time = params.start_time;
+    auction.end_time = params.end_time;
+    auction.highest_bidder = None;
+    auction.highest_bid = 0;
+    auction.bump = ctx.bumps.auction;
+    auction.escrow_bump = ctx.bumps.escrow;
+
+    emit_cpi!(AuctionCreated {
+        auction: ctx.accounts.auction.key(),
+        mint: ctx.accounts.mint.key(),
+        seller: ctx.accounts.seller.key(),
+        kind: params.kind,
+        reserve_price: params.reserve_price,
+        start_time: params.start_time,
+        end_time: params.end_time,
+    });
+
+    Ok(())
+}
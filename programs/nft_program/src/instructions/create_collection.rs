@@ -23,8 +23,9 @@ pub struct CreateCollection<'info> {
         mint::decimals = 0,
         mint::authority = mint_authority,
         mint::freeze_authority = mint_authority,
+        mint::token_program = token_program,
     )]
-    pub mint: Account<'info, Mint>,
+    pub mint: InterfaceAccount<'info, Mint>,
     
     #[account(
         seeds = [b"authority"],
@@ -36,7 +37,7 @@ pub struct CreateCollection<'info> {
     #[account(
         init,
         payer = user,
-        space = 8 + CollectionInfo::INIT_SPACE,
+        space = CollectionInfo::space(&collection_data.name, &collection_data.symbol, &collection_data.uri),
         seeds = [b"collection", mint.key().as_ref()],
         bump,
     )]
@@ -54,12 +55,38 @@ pub struct CreateCollection<'info> {
         init,
         payer = user,
         associated_token::mint = mint,
-        associated_token::authority = user
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
     )]
-    pub destination: Account<'info, TokenAccount>,
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(address = SLOT_HASHES_ID)]
+    /// CHECK: Slot hashes sysvar, seeds the starting offset. Only needed to commit a provenance hash.
+    pub slot_hashes: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Mint of the parent collection, matched against `parent_collection_info`. The
+    /// parent accounts are all omitted for a top-level collection.
+    pub parent_collection_mint: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [b"collection", parent_collection_info.mint.as_ref()],
+        bump = parent_collection_info.bump,
+    )]
+    pub parent_collection_info: Option<Box<Account<'info, CollectionInfo>>>,
+
+    #[account(mut)]
+    pub parent_metadata: Option<Box<Account<'info, MetadataAccount>>>,
+
+    /// CHECK: Master edition of the parent collection, validated by the metaplex program
+    pub parent_master_edition: Option<UncheckedAccount<'info>>,
+
+    #[account(address = INSTRUCTIONS_ID)]
+    /// CHECK: Sysvar instruction account, only needed to verify into a parent collection
+    pub sysvar_instruction: Option<UncheckedAccount<'info>>,
     
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_metadata_program: Program<'info, Metadata>,
 }
@@ -67,6 +94,7 @@ pub struct CreateCollection<'info> {
 impl validation::ValidatableData for CollectionData {
     fn name(&self) -> &str { &self.name }
     fn symbol(&self) -> &str { &self.symbol }
+    fn uri(&self) -> &str { &self.uri }
     fn seller_fee_basis_points(&self) -> u16 { self.seller_fee_basis_points }
     fn creators(&self) -> &[CreatorData] { &self.creators }
 }
@@ -74,16 +102,89 @@ impl validation::ValidatableData for CollectionData {
 impl<'info> NftUtils for CreateCollection<'info> {}
 
 impl<'info> CreateCollection<'info> {
-    fn validate_collection_data(&self, collection_data: &CollectionData) -> Result<()> {
-        collection_data.validate()
+    fn validate_collection_data(&self, collection_data: &CollectionData, provenance_hash: Option<[u8; 32]>) -> Result<()> {
+        collection_data.validate()?;
+        if provenance_hash.is_some() && self.slot_hashes.is_none() {
+            return Err(error!(NftError::MissingSlotHashes));
+        }
+
+        // Parent accounts are all passed or all omitted, a partial set is a client mistake.
+        let Some(parent_collection_info) = &self.parent_collection_info else {
+            if self.parent_collection_mint.is_some()
+                || self.parent_metadata.is_some()
+                || self.parent_master_edition.is_some()
+            {
+                return Err(error!(NftError::InvalidParentCollection));
+            }
+            return Ok(());
+        };
+        if parent_collection_info.standard != CollectionStandard::Metaplex {
+            return Err(error!(NftError::UnsupportedCollectionStandard));
+        }
+        if parent_collection_info.creator != self.user.key() {
+            return Err(error!(NftError::Unauthorized));
+        }
+
+        let parent_mint = Some(parent_collection_info.mint);
+        if self.parent_collection_mint.as_ref().map(|mint| mint.key()) != parent_mint
+            || self.parent_metadata.as_ref().map(|metadata| metadata.mint) != parent_mint
+            || self.parent_master_edition.is_none()
+            || self.sysvar_instruction.is_none()
+        {
+            return Err(error!(NftError::InvalidParentCollection));
+        }
+        Ok(())
+    }
+
+    /// Verifies the new collection into its parent through Token Metadata's sized path, with
+    /// the program PDA as the parent's update authority.
+    fn verify_into_parent(&mut self, signer_seeds: &[&[&[u8]]]) -> Result<Option<Pubkey>> {
+        let (
+            Some(parent_collection_mint),
+            Some(parent_collection_info),
+            Some(parent_metadata),
+            Some(parent_master_edition),
+            Some(sysvar_instruction),
+        ) = (
+            &self.parent_collection_mint,
+            self.parent_collection_info.as_mut(),
+            &self.parent_metadata,
+            &self.parent_master_edition,
+            &self.sysvar_instruction,
+        )
+        else {
+            return Ok(None);
+        };
+
+        VerifyCollectionV1Cpi::new(
+            &self.token_metadata_program.to_account_info(),
+            VerifyCollectionV1CpiAccounts {
+                authority: &self.mint_authority.to_account_info(),
+                delegate_record: None,
+                metadata: &self.metadata.to_account_info(),
+                collection_mint: &parent_collection_mint.to_account_info(),
+                collection_metadata: Some(&parent_metadata.to_account_info()),
+                collection_master_edition: Some(&parent_master_edition.to_account_info()),
+                system_program: &self.system_program.to_account_info(),
+                sysvar_instructions: &sysvar_instruction.to_account_info(),
+            },
+        )
+        .invoke_signed(signer_seeds)?;
+
+        parent_collection_info.number_of_nfts = parent_collection_info
+            .number_of_nfts
+            .checked_add(1)
+            .ok_or(NftError::NumericalOverflow)?;
+        Ok(Some(parent_collection_info.mint))
     }
 }
 
 pub fn create_collection(
     ctx: Context<CreateCollection>,
     collection_data: CollectionData,
+    provenance_hash: Option<[u8; 32]>,
 ) -> Result<()> {
-    ctx.accounts.validate_collection_data(&collection_data)?;
+    ctx.accounts.validate_collection_data(&collection_data, provenance_hash)?;
     
     let clock = Clock::get()?;
 
@@ -137,7 +238,10 @@ pub fn create_collection(
                 uri: collection_data.uri.clone(),
                 seller_fee_basis_points: collection_data.seller_fee_basis_points,
                 creators: Some(creators),
-                collection: None,
+                collection: ctx.accounts.parent_collection_info.as_ref().map(|parent| Collection {
+                    verified: false,
+                    key: parent.mint,
+                }),
                 uses: None,
             },
             is_mutable: true,
@@ -171,19 +275,28 @@ pub fn create_collection(
         .map_err(|_| NftError::InvalidUri)?;
     msg!("Collection Master Edition Account created");
 
-    let name_bytes = ctx.accounts.string_to_bytes::<32>(&collection_data.name);
-    let symbol_bytes = ctx.accounts.string_to_bytes::<10>(&collection_data.symbol);
-    let uri_bytes = ctx.accounts.string_to_bytes::<200>(&collection_data.uri);
+    let parent = ctx.accounts.verify_into_parent(signer_seeds)?;
+    if let Some(parent) = parent {
+        msg!("Collection verified into parent {}", parent);
+    }
 
     let collection_info = &mut ctx.accounts.collection_info;
     collection_info.mint = ctx.accounts.mint.key();
-    collection_info.name = name_bytes;   
-    collection_info.symbol = symbol_bytes;   
-    collection_info.uri = uri_bytes;        
+    collection_info.name = collection_data.name.clone();
+    collection_info.symbol = collection_data.symbol.clone();
+    collection_info.uri = collection_data.uri.clone();
     collection_info.creator = ctx.accounts.user.key();
     collection_info.created_at = clock.unix_timestamp;
     collection_info.number_of_nfts = 0;  
     collection_info.bump = ctx.bumps.collection_info;
+    collection_info.standard = CollectionStandard::Metaplex;
+    collection_info.seller_fee_basis_points = collection_data.seller_fee_basis_points;
+    collection_info.creators = collection_data.creators.clone();
+    collection_info.parent = parent;
+    if let (Some(provenance_hash), Some(slot_hashes)) = (provenance_hash, &ctx.accounts.slot_hashes) {
+        collection_info.commit_provenance(provenance_hash, recent_slot_hash(slot_hashes)?)?;
+        msg!("Provenance hash committed!");
+    }
 
     emit_cpi!(CollectionCreated {
         mint: ctx.accounts.mint.key(),
@@ -192,6 +305,9 @@ pub fn create_collection(
         uri: collection_data.uri,
         creator: ctx.accounts.user.key(),
         created_at: clock.unix_timestamp,
+        provenance_hash: ctx.accounts.collection_info.provenance_hash,
+        starting_offset: ctx.accounts.collection_info.starting_offset,
+        parent,
     });
     
     Ok(())
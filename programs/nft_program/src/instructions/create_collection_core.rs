@@ -0,0 +1,100 @@
+use super::*;
+use crate::instructions::shared::validation::ValidatableData;
+
+/// Creates a Metaplex Core collection with the program PDA as update authority. The Core
+/// collection address takes the place of the collection mint in `CollectionInfo`.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(collection_data: CollectionData)]
+pub struct CreateCollectionCore<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Fresh keypair, initialized by the Metaplex Core program.
+    #[account(mut)]
+    pub collection: Signer<'info>,
+
+    #[account(
+        seeds = [b"authority"],
+        bump,
+    )]
+    /// CHECK: This account is used for signing purposes only
+    pub mint_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = user,
+        space = CollectionInfo::space(&collection_data.name, &collection_data.symbol, &collection_data.uri),
+        seeds = [b"collection", collection.key().as_ref()],
+        bump,
+    )]
+    pub collection_info: Box<Account<'info, CollectionInfo>>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(address = MPL_CORE_ID)]
+    /// CHECK: Metaplex Core program, checked with an address constraint
+    pub mpl_core_program: UncheckedAccount<'info>,
+}
+
+impl<'info> CreateCollectionCore<'info> {
+    fn validate_collection_data(&self, collection_data: &CollectionData) -> Result<()> {
+        collection_data.validate()
+    }
+}
+
+pub fn create_collection_core(
+    ctx: Context<CreateCollectionCore>,
+    collection_data: CollectionData,
+) -> Result<()> {
+    ctx.accounts.validate_collection_data(&collection_data)?;
+
+    let clock = Clock::get()?;
+
+    let authority_bump = ctx.bumps.mint_authority;
+    let seeds = &[&b"authority"[..], &[authority_bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    CoreAccounts {
+        mpl_core_program: &ctx.accounts.mpl_core_program.to_account_info(),
+        payer: &ctx.accounts.user.to_account_info(),
+        authority: &ctx.accounts.mint_authority.to_account_info(),
+        system_program: &ctx.accounts.system_program.to_account_info(),
+    }
+    .create_collection(
+        &ctx.accounts.collection.to_account_info(),
+        signer_seeds,
+        collection_data.name.clone(),
+        collection_data.uri.clone(),
+        collection_data.seller_fee_basis_points,
+        &collection_data.creators,
+    )?;
+    msg!("Core collection created!");
+
+    let collection_info = &mut ctx.accounts.collection_info;
+    collection_info.mint = ctx.accounts.collection.key();
+    collection_info.name = collection_data.name.clone();
+    collection_info.symbol = collection_data.symbol.clone();
+    collection_info.uri = collection_data.uri.clone();
+    collection_info.creator = ctx.accounts.user.key();
+    collection_info.created_at = clock.unix_timestamp;
+    collection_info.number_of_nfts = 0;
+    collection_info.bump = ctx.bumps.collection_info;
+    collection_info.standard = CollectionStandard::Core;
+    collection_info.seller_fee_basis_points = collection_data.seller_fee_basis_points;
+    collection_info.creators = collection_data.creators.clone();
+
+    emit_cpi!(CollectionCreated {
+        mint: ctx.accounts.collection.key(),
+        name: collection_data.name,
+        symbol: collection_data.symbol,
+        uri: collection_data.uri,
+        creator: ctx.accounts.user.key(),
+        created_at: clock.unix_timestamp,
+        provenance_hash: None,
+        starting_offset: None,
+        parent: None,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,153 @@
+use super::*;
+use crate::instructions::shared::validation::ValidatableData;
+
+/// Creates a native Token-2022 collection: a mint carrying its own token metadata and the
+/// token-group extension, with the program PDA as group update authority.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(collection_data: CollectionData)]
+pub struct CreateCollectionToken2022<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        init,
+        payer = user,
+        mint::decimals = 0,
+        mint::authority = mint_authority,
+        mint::freeze_authority = mint_authority,
+        mint::token_program = token_program,
+        extensions::metadata_pointer::authority = mint_authority,
+        extensions::metadata_pointer::metadata_address = mint,
+        extensions::group_pointer::authority = mint_authority,
+        extensions::group_pointer::group_address = mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [b"authority"],
+        bump,
+    )]
+    /// CHECK: This account is used for signing purposes only
+    pub mint_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = user,
+        space = CollectionInfo::space(&collection_data.name, &collection_data.symbol, &collection_data.uri),
+        seeds = [b"collection", mint.key().as_ref()],
+        bump,
+    )]
+    pub collection_info: Box<Account<'info, CollectionInfo>>,
+
+    #[account(
+        init,
+        payer = user,
+        associated_token::mint = mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub destination: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> CreateCollectionToken2022<'info> {
+    fn validate_collection_data(&self, collection_data: &CollectionData, max_size: u64) -> Result<()> {
+        if max_size == 0 {
+            return Err(error!(NftError::InvalidMaxSize));
+        }
+        collection_data.validate()
+    }
+}
+
+pub fn create_collection_token_2022(
+    ctx: Context<CreateCollectionToken2022>,
+    collection_data: CollectionData,
+    max_size: u64,
+) -> Result<()> {
+    ctx.accounts.validate_collection_data(&collection_data, max_size)?;
+
+    let clock = Clock::get()?;
+
+    let payer = &ctx.accounts.user.to_account_info();
+    let mint = &ctx.accounts.mint.to_account_info();
+    let authority = &ctx.accounts.mint_authority.to_account_info();
+    let token_program = &ctx.accounts.token_program.to_account_info();
+    let system_program = &ctx.accounts.system_program.to_account_info();
+
+    let authority_bump = ctx.bumps.mint_authority;
+    let seeds = &[&b"authority"[..], &[authority_bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    let token_2022 = Token2022MintAccounts {
+        payer,
+        mint,
+        authority,
+        token_program,
+        system_program,
+    };
+
+    token_2022.initialize_token_metadata(
+        signer_seeds,
+        collection_data.name.clone(),
+        collection_data.symbol.clone(),
+        collection_data.uri.clone(),
+        royalty_fields(collection_data.seller_fee_basis_points, &collection_data.creators),
+    )?;
+    msg!("Collection Token Metadata initialized!");
+
+    fund_account_growth(
+        mint,
+        payer,
+        system_program,
+        mint_extension_tlv_len(ExtensionType::TokenGroup)?,
+    )?;
+    token_group_initialize(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            TokenGroupInitialize {
+                program_id: token_program.clone(),
+                group: mint.clone(),
+                mint: mint.clone(),
+                mint_authority: authority.clone(),
+            },
+            signer_seeds,
+        ),
+        Some(authority.key()),
+        max_size,
+    )?;
+    msg!("Collection Token Group initialized with max size {}", max_size);
+
+    token_2022.mint_one_and_lock(&ctx.accounts.destination.to_account_info(), signer_seeds)?;
+    msg!("Collection NFT minted!");
+
+    let collection_info = &mut ctx.accounts.collection_info;
+    collection_info.mint = ctx.accounts.mint.key();
+    collection_info.name = collection_data.name.clone();
+    collection_info.symbol = collection_data.symbol.clone();
+    collection_info.uri = collection_data.uri.clone();
+    collection_info.creator = ctx.accounts.user.key();
+    collection_info.created_at = clock.unix_timestamp;
+    collection_info.number_of_nfts = 0;
+    collection_info.bump = ctx.bumps.collection_info;
+    collection_info.standard = CollectionStandard::Token2022Group;
+    collection_info.seller_fee_basis_points = collection_data.seller_fee_basis_points;
+    collection_info.creators = collection_data.creators.clone();
+
+    emit_cpi!(CollectionCreated {
+        mint: ctx.accounts.mint.key(),
+        name: collection_data.name,
+        symbol: collection_data.symbol,
+        uri: collection_data.uri,
+        creator: ctx.accounts.user.key(),
+        created_at: clock.unix_timestamp,
+        provenance_hash: None,
+        starting_offset: None,
+        parent: None,
+    });
+
+    Ok(())
+}
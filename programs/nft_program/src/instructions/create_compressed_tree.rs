@@ -0,0 +1,110 @@
+use super::*;
+
+/// Turns an allocated account compression Merkle tree into the collection's private Bubblegum
+/// tree, with the program PDA as tree creator and delegate.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateCompressedTree<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub collection_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [b"collection", collection_mint.key().as_ref()],
+        bump = collection_info.bump,
+        has_one = creator @ NftError::Unauthorized,
+    )]
+    pub collection_info: Box<Account<'info, CollectionInfo>>,
+
+    #[account(
+        mut,
+        owner = ACCOUNT_COMPRESSION_ID,
+    )]
+    /// CHECK: Allocated by the client and initialized by the account compression program
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+        seeds::program = BUBBLEGUM_ID,
+    )]
+    /// CHECK: Tree config, initialized by the Bubblegum program
+    pub tree_authority: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"authority"],
+        bump,
+    )]
+    /// CHECK: This account is used for signing purposes only
+    pub mint_authority: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(address = BUBBLEGUM_ID)]
+    /// CHECK: Bubblegum program, checked with an address constraint
+    pub bubblegum_program: UncheckedAccount<'info>,
+
+    #[account(address = NOOP_ID)]
+    /// CHECK: Noop program, checked with an address constraint
+    pub log_wrapper: UncheckedAccount<'info>,
+
+    #[account(address = ACCOUNT_COMPRESSION_ID)]
+    /// CHECK: Account compression program, checked with an address constraint
+    pub compression_program: UncheckedAccount<'info>,
+}
+
+impl<'info> CreateCompressedTree<'info> {
+    fn validate_tree(&self) -> Result<()> {
+        if self.collection_info.standard != CollectionStandard::Metaplex {
+            return Err(error!(NftError::UnsupportedCollectionStandard));
+        }
+        if self.collection_info.merkle_tree.is_some() {
+            return Err(error!(NftError::MerkleTreeAlreadyCreated));
+        }
+        Ok(())
+    }
+}
+
+pub fn create_compressed_tree(
+    ctx: Context<CreateCompressedTree>,
+    max_depth: u32,
+    max_buffer_size: u32,
+) -> Result<()> {
+    ctx.accounts.validate_tree()?;
+
+    let clock = Clock::get()?;
+
+    let authority_bump = ctx.bumps.mint_authority;
+    let seeds = &[&b"authority"[..], &[authority_bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    BubblegumAccounts {
+        bubblegum_program: &ctx.accounts.bubblegum_program.to_account_info(),
+        tree_authority: &ctx.accounts.tree_authority.to_account_info(),
+        merkle_tree: &ctx.accounts.merkle_tree.to_account_info(),
+        payer: &ctx.accounts.creator.to_account_info(),
+        authority: &ctx.accounts.mint_authority.to_account_info(),
+        log_wrapper: &ctx.accounts.log_wrapper.to_account_info(),
+        compression_program: &ctx.accounts.compression_program.to_account_info(),
+        system_program: &ctx.accounts.system_program.to_account_info(),
+    }
+    .create_tree(signer_seeds, max_depth, max_buffer_size)?;
+    msg!("Merkle tree created with max depth {}", max_depth);
+
+    let collection_info = &mut ctx.accounts.collection_info;
+    collection_info.merkle_tree = Some(ctx.accounts.merkle_tree.key());
+    collection_info.compressed_leaf_count = 0;
+
+    emit_cpi!(CompressedTreeCreated {
+        collection_mint: ctx.accounts.collection_mint.key(),
+        merkle_tree: ctx.accounts.merkle_tree.key(),
+        max_depth,
+        max_buffer_size,
+        created_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
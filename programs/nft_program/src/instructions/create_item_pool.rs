@@ -0,0 +1,71 @@
+use super::*;
+
+/// Creates the `ItemPool` of a collection holding `capacity` items. The creator then uploads
+/// the items in chunks with `add_pool_items`, and `mint_from_pool` opens once all are loaded.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(capacity: u32)]
+pub struct CreateItemPool<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub collection_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [b"collection", collection_mint.key().as_ref()],
+        bump = collection_info.bump,
+        has_one = creator @ NftError::Unauthorized,
+    )]
+    pub collection_info: Box<Account<'info, CollectionInfo>>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = ItemPool::space(capacity, 0),
+        seeds = [b"item_pool", collection_mint.key().as_ref()],
+        bump,
+    )]
+    pub item_pool: Box<Account<'info, ItemPool>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateItemPool<'info> {
+    fn validate_pool(&self, capacity: u32, seller_fee_basis_points: u16, creators: &[CreatorData]) -> Result<()> {
+        if self.collection_info.standard != CollectionStandard::Metaplex {
+            return Err(error!(NftError::UnsupportedCollectionStandard));
+        }
+        if capacity == 0 || capacity > ItemPool::MAX_CAPACITY {
+            return Err(error!(NftError::InvalidPoolCapacity));
+        }
+        validation::validate_seller_fee_basis_points(seller_fee_basis_points)?;
+        validation::validate_creators(creators)
+    }
+}
+
+pub fn create_item_pool(
+    ctx: Context<CreateItemPool>,
+    capacity: u32,
+    seller_fee_basis_points: u16,
+    creators: Vec<CreatorData>,
+) -> Result<()> {
+    ctx.accounts.validate_pool(capacity, seller_fee_basis_points, &creators)?;
+
+    let item_pool = &mut ctx.accounts.item_pool;
+    item_pool.collection_mint = ctx.accounts.collection_mint.key();
+    item_pool.creator = ctx.accounts.creator.key();
+    item_pool.capacity = capacity;
+    item_pool.loaded = 0;
+    item_pool.minted = 0;
+    item_pool.seller_fee_basis_points = seller_fee_basis_points;
+    item_pool.creators = creators;
+    item_pool.bump = ctx.bumps.item_pool;
+
+    emit_cpi!(ItemPoolCreated {
+        item_pool: ctx.accounts.item_pool.key(),
+        collection_mint: ctx.accounts.collection_mint.key(),
+        capacity,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use super::*;
+
+/// Opens a staking pool for a collection. Only the collection creator can create it, once.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateStakingPool<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"collection", collection_info.mint.as_ref()],
+        bump = collection_info.bump,
+        constraint = collection_info.creator == authority.key() @ NftError::Unauthorized,
+    )]
+    pub collection_info: Box<Account<'info, CollectionInfo>>,
+
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = StakingPool::SPACE,
+        seeds = [b"staking_pool", collection_info.mint.as_ref()],
+        bump,
+    )]
+    pub staking_pool: Box<Account<'info, StakingPool>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_staking_pool(
+    ctx: Context<CreateStakingPool>,
+    reward_rate: u64,
+) -> Result<()> {
+    if reward_rate == 0 {
+        return Err(error!(NftError::InvalidRewardRate));
+    }
+
+    let clock = Clock::get()?;
+
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    staking_pool.collection_mint = ctx.accounts.collection_info.mint;
+    staking_pool.authority = ctx.accounts.authority.key();
+    staking_pool.reward_mint = ctx.accounts.reward_mint.key();
+    staking_pool.reward_rate = reward_rate;
+    staking_pool.total_staked = 0;
+    staking_pool.created_at = clock.unix_timestamp;
+    staking_pool.bump = ctx.bumps.staking_pool;
+    msg!("Staking pool created with reward rate {}", reward_rate);
+
+    emit_cpi!(StakingPoolCreated {
+        pool: ctx.accounts.staking_pool.key(),
+        collection_mint: ctx.accounts.collection_info.mint,
+        reward_mint: ctx.accounts.reward_mint.key(),
+        reward_rate,
+        authority: ctx.accounts.authority.key(),
+        created_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
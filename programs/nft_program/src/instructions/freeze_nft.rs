@@ -0,0 +1,148 @@
+use super::*;
+
+/// Locks an NFT in its owner's wallet for soft staking, or unlocks it again. The NFT never leaves
+/// the owner's token account.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct NftFreeze<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Master edition of `mint`, validated by the metaplex program. Omit for Token-2022 NFTs.
+    pub edition: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        seeds = [b"authority"],
+        bump,
+    )]
+    /// CHECK: This account is used for signing purposes only
+    pub mint_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"nft", mint.key().as_ref()],
+        bump = nft_info.bump,
+    )]
+    pub nft_info: Option<Account<'info, NftInfo>>,
+
+    /// CHECK: Stake record PDA of `mint`, must not exist. Checked by `NftLockAccounts`
+    pub stake_record: UncheckedAccount<'info>,
+
+    /// CHECK: Rental agreement PDA of `mint`, must not exist. Checked by `NftLockAccounts`
+    pub rental_agreement: UncheckedAccount<'info>,
+
+    /// CHECK: Listing PDA of `mint`, must not exist. Checked by `NftLockAccounts`
+    pub listing: UncheckedAccount<'info>,
+
+    /// CHECK: Auction PDA of `mint`, must not exist. Checked by `NftLockAccounts`
+    pub auction: UncheckedAccount<'info>,
+
+    /// CHECK: Soulbound record PDA of `mint`, must not exist. Checked by `NftLockAccounts`
+    pub soulbound_record: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub token_metadata_program: Program<'info, Metadata>,
+}
+
+impl<'info> NftFreeze<'info> {
+    fn require_unlocked(&self) -> Result<()> {
+        NftLockAccounts {
+            stake_record: &self.stake_record.to_account_info(),
+            rental_agreement: &self.rental_agreement.to_account_info(),
+            listing: &self.listing.to_account_info(),
+            auction: &self.auction.to_account_info(),
+            soulbound_record: &self.soulbound_record.to_account_info(),
+        }
+        .require_unlocked(&self.mint.key())
+    }
+}
+
+pub fn freeze_nft(ctx: Context<NftFreeze>) -> Result<()> {
+    ctx.accounts.require_unlocked()?;
+    if ctx.accounts.token.is_frozen() {
+        return Err(error!(NftError::AlreadyFrozen));
+    }
+
+    let clock = Clock::get()?;
+
+    let authority_bump = ctx.bumps.mint_authority;
+    let seeds = &[&b"authority"[..], &[authority_bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    let edition = ctx.accounts.edition.as_ref().map(|edition| edition.to_account_info());
+    NftFreezeAccounts {
+        token_program: &ctx.accounts.token_program.to_account_info(),
+        token_metadata_program: &ctx.accounts.token_metadata_program.to_account_info(),
+        token: &ctx.accounts.token.to_account_info(),
+        mint: &ctx.accounts.mint.to_account_info(),
+        edition: edition.as_ref(),
+        owner: &ctx.accounts.owner.to_account_info(),
+        authority: &ctx.accounts.mint_authority.to_account_info(),
+    }
+    .freeze(signer_seeds)?;
+    msg!("NFT frozen!");
+
+    if let Some(nft_info) = ctx.accounts.nft_info.as_mut() {
+        nft_info.frozen_since = Some(clock.unix_timestamp);
+    }
+
+    emit_cpi!(NftFrozen {
+        mint: ctx.accounts.mint.key(),
+        owner: ctx.accounts.owner.key(),
+        frozen_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn thaw_nft(ctx: Context<NftFreeze>) -> Result<()> {
+    ctx.accounts.require_unlocked()?;
+    if !ctx.accounts.token.is_frozen() {
+        return Err(error!(NftError::NotFrozen));
+    }
+
+    let clock = Clock::get()?;
+
+    let authority_bump = ctx.bumps.mint_authority;
+    let seeds = &[&b"authority"[..], &[authority_bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    let edition = ctx.accounts.edition.as_ref().map(|edition| edition.to_account_info());
+    NftFreezeAccounts {
+        token_program: &ctx.accounts.token_program.to_account_info(),
+        token_metadata_program: &ctx.accounts.token_metadata_program.to_account_info(),
+        token: &ctx.accounts.token.to_account_info(),
+        mint: &ctx.accounts.mint.to_account_info(),
+        edition: edition.as_ref(),
+        owner: &ctx.accounts.owner.to_account_info(),
+        authority: &ctx.accounts.mint_authority.to_account_info(),
+    }
+    .thaw(signer_seeds)?;
+    msg!("NFT thawed!");
+
+    let frozen_since = ctx
+        .accounts
+        .nft_info
+        .as_mut()
+        .and_then(|nft_info| nft_info.frozen_since.take());
+
+    emit_cpi!(NftThawed {
+        mint: ctx.accounts.mint.key(),
+        owner: ctx.accounts.owner.key(),
+        frozen_since,
+        thawed_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
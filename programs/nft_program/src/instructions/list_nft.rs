@@ -0,0 +1,89 @@
+use super::*;
+
+/// Lists an NFT for sale at `price` lamports. The NFT stays in the seller's wallet, frozen with
+/// the program PDA as delegate until it is sold or the listing is canceled.
+/// Only SPL Token NFTs with Token Metadata can be listed, since `buy_nft` pays royalties from it.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ListNFT<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = seller,
+        associated_token::token_program = token_program,
+        constraint = token.amount == 1 @ NftError::Unauthorized,
+    )]
+    pub token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Master edition of `mint`, validated by the metaplex program
+    pub edition: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        seeds = [b"authority"],
+        bump,
+    )]
+    /// CHECK: This account is used for signing purposes only
+    pub mint_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = seller,
+        space = Listing::SPACE,
+        seeds = [b"listing", mint.key().as_ref()],
+        bump,
+    )]
+    pub listing: Box<Account<'info, Listing>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub token_metadata_program: Program<'info, Metadata>,
+}
+
+pub fn list_nft(ctx: Context<ListNFT>, price: u64) -> Result<()> {
+    if price == 0 {
+        return Err(error!(NftError::InvalidPrice));
+    }
+    if ctx.accounts.token.is_frozen() {
+        return Err(error!(NftError::AlreadyFrozen));
+    }
+
+    let authority_bump = ctx.bumps.mint_authority;
+    let seeds = &[&b"authority"[..], &[authority_bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    let edition = ctx.accounts.edition.as_ref().map(|edition| edition.to_account_info());
+    NftFreezeAccounts {
+        token_program: &ctx.accounts.token_program.to_account_info(),
+        token_metadata_program: &ctx.accounts.token_metadata_program.to_account_info(),
+        token: &ctx.accounts.token.to_account_info(),
+        mint: &ctx.accounts.mint.to_account_info(),
+        edition: edition.as_ref(),
+        owner: &ctx.accounts.seller.to_account_info(),
+        authority: &ctx.accounts.mint_authority.to_account_info(),
+    }
+    .freeze(signer_seeds)?;
+    msg!("NFT frozen for sale!");
+
+    let clock = Clock::get()?;
+    let listing = &mut ctx.accounts.listing;
+    listing.mint = ctx.accounts.mint.key();
+    listing.seller = ctx.accounts.seller.key();
+    listing.price = price;
+    listing.listed_at = clock.unix_timestamp;
+    listing.bump = ctx.bumps.listing;
+
+    emit_cpi!(NftListed {
+        mint: ctx.accounts.mint.key(),
+        seller: ctx.accounts.seller.key(),
+        price,
+        listed_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
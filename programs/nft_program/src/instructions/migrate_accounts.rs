@@ -0,0 +1,197 @@
+use super::*;
+
+#[derive(Accounts)]
+pub struct MigrateCollectionInfo<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub collection_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"collection", collection_mint.key().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    /// CHECK: Legacy layout no longer deserializes as `CollectionInfo`, parsed by hand below
+    pub collection_info: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Signed by the current holder, proven by `token`, or by the collection creator, proven by
+/// `collection_info`. The legacy `owner` field goes stale on transfers, so it is not trusted.
+#[derive(Accounts)]
+pub struct MigrateNftInfo<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(token::mint = mint)]
+    pub token: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Must already be migrated, see `migrate_collection_info`.
+    #[account(
+        seeds = [b"collection", collection_info.mint.as_ref()],
+        bump = collection_info.bump,
+    )]
+    pub collection_info: Option<Box<Account<'info, CollectionInfo>>>,
+
+    #[account(
+        mut,
+        seeds = [b"nft", mint.key().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    /// CHECK: Legacy layout no longer deserializes as `NftInfo`, parsed by hand below
+    pub nft_info: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> NftUtils for MigrateCollectionInfo<'info> {}
+impl<'info> NftUtils for MigrateNftInfo<'info> {}
+
+impl<'info> MigrateNftInfo<'info> {
+    fn validate_authority(&self, legacy: &LegacyNftInfo) -> Result<()> {
+        let authority = self.authority.key();
+        let holder = self
+            .token
+            .as_ref()
+            .is_some_and(|token| token.owner == authority && token.amount == 1);
+        let creator = self
+            .collection_info
+            .as_ref()
+            .is_some_and(|info| info.mint == legacy.collection_mint && info.creator == authority);
+        if !holder && !creator {
+            return Err(error!(NftError::Unauthorized));
+        }
+        Ok(())
+    }
+}
+
+/// Checks the discriminator and that `data` is not already in the current layout,
+/// then returns the legacy body.
+fn legacy_body<'a, T: AccountDeserialize>(
+    data: &'a [u8],
+    discriminator: &[u8],
+    legacy_space: usize,
+) -> Result<&'a [u8]> {
+    if !data.starts_with(discriminator) {
+        return Err(error!(NftError::InvalidLegacyAccount));
+    }
+
+    let mut current = data;
+    if T::try_deserialize(&mut current).is_ok() && current.is_empty() {
+        return Err(error!(NftError::AccountAlreadyMigrated));
+    }
+
+    if data.len() != legacy_space {
+        return Err(error!(NftError::InvalidLegacyAccount));
+    }
+    Ok(&data[discriminator.len()..])
+}
+
+/// `next_index` is supplied by the creator: legacy collections never recorded their mint order,
+/// and `number_of_nfts` only counts verified items.
+pub fn migrate_collection_info(ctx: Context<MigrateCollectionInfo>, next_index: u64) -> Result<()> {
+    let account_info = ctx.accounts.collection_info.to_account_info();
+
+    let migrated = {
+        let data = account_info.try_borrow_data()?;
+        let mut body = legacy_body::<CollectionInfo>(
+            &data,
+            CollectionInfo::DISCRIMINATOR,
+            LegacyCollectionInfo::SPACE,
+        )?;
+        let legacy = LegacyCollectionInfo::deserialize(&mut body)?;
+
+        if legacy.creator != ctx.accounts.creator.key() {
+            return Err(error!(NftError::Unauthorized));
+        }
+
+        CollectionInfo {
+            mint: legacy.mint,
+            name: ctx.accounts.bytes_to_string(&legacy.name),
+            symbol: ctx.accounts.bytes_to_string(&legacy.symbol),
+            uri: ctx.accounts.bytes_to_string(&legacy.uri),
+            creator: legacy.creator,
+            number_of_nfts: legacy.number_of_nfts,
+            created_at: legacy.created_at,
+            bump: legacy.bump,
+            standard: CollectionStandard::Metaplex,
+            merkle_tree: None,
+            compressed_leaf_count: 0,
+            next_index,
+            placeholder_uri: String::new(),
+            revealed: false,
+            reveal_supply: None,
+            base_uri: String::new(),
+            revealed_count: 0,
+            provenance_hash: None,
+            starting_offset: None,
+            parent: None,
+            seller_fee_basis_points: 0,
+            creators: Vec::new(),
+            enforce_royalties: false,
+            soulbound: false,
+        }
+    };
+
+    resize_with_rent(
+        &account_info,
+        &ctx.accounts.creator.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        CollectionInfo::space(&migrated.name, &migrated.symbol, &migrated.uri),
+    )?;
+
+    let mut data = account_info.try_borrow_mut_data()?;
+    migrated.try_serialize(&mut &mut data[..])?;
+
+    msg!("CollectionInfo migrated");
+    Ok(())
+}
+
+pub fn migrate_nft_info(ctx: Context<MigrateNftInfo>) -> Result<()> {
+    let account_info = ctx.accounts.nft_info.to_account_info();
+
+    let migrated = {
+        let data = account_info.try_borrow_data()?;
+        let mut body = legacy_body::<NftInfo>(&data, NftInfo::DISCRIMINATOR, LegacyNftInfo::SPACE)?;
+        let legacy = LegacyNftInfo::deserialize(&mut body)?;
+
+        ctx.accounts.validate_authority(&legacy)?;
+
+        NftInfo {
+            mint: legacy.mint,
+            collection_mint: legacy.collection_mint,
+            name: ctx.accounts.bytes_to_string(&legacy.name),
+            symbol: ctx.accounts.bytes_to_string(&legacy.symbol),
+            uri: ctx.accounts.bytes_to_string(&legacy.uri),
+            owner: legacy.owner,
+            verified: legacy.verified,
+            minted_at: legacy.minted_at,
+            bump: legacy.bump,
+            frozen_since: None,
+            index: None,
+            uses: None,
+            remaining_uses: 0,
+            revealed: false,
+            use_authority: None,
+        }
+    };
+
+    resize_with_rent(
+        &account_info,
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        NftInfo::space(&migrated.name, &migrated.symbol, &migrated.uri),
+    )?;
+
+    let mut data = account_info.try_borrow_mut_data()?;
+    migrated.try_serialize(&mut &mut data[..])?;
+
+    msg!("NftInfo migrated");
+    Ok(())
+}
@@ -0,0 +1,153 @@
+use super::*;
+use crate::instructions::shared::validation::ValidatableData;
+
+/// Mints a compressed NFT into the collection's Bubblegum tree, verified into the collection in
+/// the same instruction. No mint, token, metadata or `NftInfo` account is created.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MintCompressedNFT<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Any wallet can own the compressed NFT
+    pub leaf_owner: UncheckedAccount<'info>,
+
+    pub collection_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [b"collection", collection_mint.key().as_ref()],
+        bump = collection_info.bump,
+    )]
+    pub collection_info: Box<Account<'info, CollectionInfo>>,
+
+    #[account(mut)]
+    /// CHECK: Metadata of the collection, validated by the metaplex program
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Master edition of the collection, validated by the metaplex program
+    pub collection_master_edition: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Must match the tree recorded on `collection_info`
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+        seeds::program = BUBBLEGUM_ID,
+    )]
+    /// CHECK: Tree config of `merkle_tree`, owned by the Bubblegum program
+    pub tree_authority: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"authority"],
+        bump,
+    )]
+    /// CHECK: This account is used for signing purposes only
+    pub mint_authority: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [BUBBLEGUM_COLLECTION_CPI_SEED],
+        bump,
+        seeds::program = BUBBLEGUM_ID,
+    )]
+    /// CHECK: Bubblegum's collection signer PDA
+    pub bubblegum_signer: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_metadata_program: Program<'info, Metadata>,
+
+    #[account(address = BUBBLEGUM_ID)]
+    /// CHECK: Bubblegum program, checked with an address constraint
+    pub bubblegum_program: UncheckedAccount<'info>,
+
+    #[account(address = NOOP_ID)]
+    /// CHECK: Noop program, checked with an address constraint
+    pub log_wrapper: UncheckedAccount<'info>,
+
+    #[account(address = ACCOUNT_COMPRESSION_ID)]
+    /// CHECK: Account compression program, checked with an address constraint
+    pub compression_program: UncheckedAccount<'info>,
+}
+
+impl<'info> MintCompressedNFT<'info> {
+    fn validate_nft_data(&self, nft_data: &NftData) -> Result<()> {
+        nft_data.reject_uses()?;
+        // A compressed NFT has no `NftInfo` for `reveal` to rewrite, nor a mint index.
+        if self.collection_info.reveal_pending() || self.collection_info.reveal_supply.is_some() {
+            return Err(error!(NftError::RevealUnsupported));
+        }
+        // Compressed NFTs have no token account to freeze as soulbound.
+        self.collection_info.require_soulbound(false)?;
+        if self.collection_info.merkle_tree != Some(self.merkle_tree.key()) {
+            return Err(error!(NftError::InvalidMerkleTree));
+        }
+        nft_data.validate()
+    }
+}
+
+pub fn mint_compressed_nft(
+    ctx: Context<MintCompressedNFT>,
+    mut nft_data: NftData,
+) -> Result<()> {
+    nft_data.inherit_royalties(&ctx.accounts.collection_info)?;
+    ctx.accounts.validate_nft_data(&nft_data)?;
+
+    let clock = Clock::get()?;
+
+    let tree_authority = &ctx.accounts.tree_authority.to_account_info();
+    let leaf_index = tree_num_minted(tree_authority)?;
+
+    let authority_bump = ctx.bumps.mint_authority;
+    let seeds = &[&b"authority"[..], &[authority_bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    BubblegumAccounts {
+        bubblegum_program: &ctx.accounts.bubblegum_program.to_account_info(),
+        tree_authority,
+        merkle_tree: &ctx.accounts.merkle_tree.to_account_info(),
+        payer: &ctx.accounts.payer.to_account_info(),
+        authority: &ctx.accounts.mint_authority.to_account_info(),
+        log_wrapper: &ctx.accounts.log_wrapper.to_account_info(),
+        compression_program: &ctx.accounts.compression_program.to_account_info(),
+        system_program: &ctx.accounts.system_program.to_account_info(),
+    }
+    .mint_to_collection(
+        &BubblegumCollectionAccounts {
+            collection_mint: &ctx.accounts.collection_mint.to_account_info(),
+            collection_metadata: &ctx.accounts.collection_metadata.to_account_info(),
+            collection_master_edition: &ctx.accounts.collection_master_edition.to_account_info(),
+            bubblegum_signer: &ctx.accounts.bubblegum_signer.to_account_info(),
+            token_metadata_program: &ctx.accounts.token_metadata_program.to_account_info(),
+        },
+        &ctx.accounts.leaf_owner.to_account_info(),
+        signer_seeds,
+        &nft_data,
+    )?;
+    msg!("Compressed NFT minted at leaf {}", leaf_index);
+
+    let collection_info = &mut ctx.accounts.collection_info;
+    collection_info.compressed_leaf_count = collection_info
+        .compressed_leaf_count
+        .checked_add(1)
+        .ok_or(NftError::NumericalOverflow)?;
+    collection_info.number_of_nfts = collection_info
+        .number_of_nfts
+        .checked_add(1)
+        .ok_or(NftError::NumericalOverflow)?;
+
+    emit_cpi!(CompressedNftMinted {
+        merkle_tree: ctx.accounts.merkle_tree.key(),
+        leaf_index,
+        collection_mint: ctx.accounts.collection_mint.key(),
+        name: nft_data.name,
+        symbol: nft_data.symbol,
+        uri: nft_data.uri,
+        owner: ctx.accounts.leaf_owner.key(),
+        minted_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,248 @@
+use super::*;
+use crate::instructions::shared::validation::ValidatableData;
+
+/// Mints the next NFT of a collection from its `ItemPool`. The item is drawn among the ones not
+/// minted yet from the most recent slot hash and the pool's mint count only, nothing the minter
+/// controls, and the draw must be the last top-level instruction so it cannot be reverted.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MintFromPool<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        init,
+        payer = owner,
+        mint::decimals = 0,
+        mint::authority = mint_authority,
+        mint::freeze_authority = mint_authority,
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = owner,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub destination: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [b"authority"],
+        bump,
+    )]
+    /// CHECK: This account is used for signing purposes only
+    pub mint_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"collection", collection_mint.key().as_ref()],
+        bump = collection_info.bump,
+    )]
+    pub collection_info: Box<Account<'info, CollectionInfo>>,
+
+    #[account(
+        mut,
+        seeds = [b"item_pool", collection_mint.key().as_ref()],
+        bump = item_pool.bump,
+    )]
+    pub item_pool: Box<Account<'info, ItemPool>>,
+
+    /// Created without room for the name and URI, which are only known once the item is drawn;
+    /// the handler then grows it to `NftInfo::space` of the drawn item.
+    #[account(
+        init,
+        payer = owner,
+        space = NftInfo::space("", &collection_info.symbol, ""),
+        seeds = [b"nft", mint.key().as_ref()],
+        bump,
+    )]
+    pub nft_info: Box<Account<'info, NftInfo>>,
+
+    /// Pass to mint a soulbound NFT, frozen in the owner's wallet until `revoke_soulbound`.
+    /// Required when the collection is soulbound.
+    #[account(
+        init,
+        payer = owner,
+        space = SoulboundRecord::SPACE,
+        seeds = [b"soulbound", mint.key().as_ref()],
+        bump,
+    )]
+    pub soulbound_record: Option<Box<Account<'info, SoulboundRecord>>>,
+
+    #[account(mut)]
+    /// CHECK: This account will be initialized by the metaplex program
+    pub metadata: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: This account will be initialized by the metaplex program
+    pub master_edition: UncheckedAccount<'info>,
+
+    pub collection_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(address = SLOT_HASHES_ID)]
+    /// CHECK: Slot hashes sysvar, source of the item draw
+    pub slot_hashes: UncheckedAccount<'info>,
+
+    #[account(address = INSTRUCTIONS_ID)]
+    /// CHECK: Sysvar instruction account that is being checked with an address constraint
+    pub sysvar_instruction: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
+}
+
+impl<'info> NftUtils for MintFromPool<'info> {}
+
+impl<'info> MintFromPool<'info> {
+    fn validate_pool(&self) -> Result<u32> {
+        self.collection_info.require_soulbound(self.soulbound_record.is_some())?;
+        if self.item_pool.loaded < self.item_pool.capacity {
+            return Err(error!(NftError::PoolNotLoaded));
+        }
+        let remaining = self.item_pool.capacity - self.item_pool.minted;
+        if remaining == 0 {
+            return Err(error!(NftError::PoolEmpty));
+        }
+        Ok(remaining)
+    }
+
+    /// Marks a random unminted item as minted and returns its index and config.
+    fn draw_item(&mut self, remaining: u32) -> Result<(u32, PoolItem)> {
+        require_isolated_instruction(&self.sysvar_instruction)?;
+        let slot_hash = recent_slot_hash(&self.slot_hashes)?;
+        let seed = hashv(&[&slot_hash, &self.item_pool.minted.to_le_bytes()]).to_bytes();
+        let mut draw = [0u8; 8];
+        draw.copy_from_slice(&seed[..8]);
+        let nth = (u64::from_le_bytes(draw) % remaining as u64) as u32;
+
+        let account_info = self.item_pool.to_account_info();
+        let mut data = account_info.try_borrow_mut_data()?;
+        let index = self.item_pool.take_unminted(&mut data, nth)?;
+        let item = self.item_pool.read_item(&data, index)?;
+        drop(data);
+
+        self.item_pool.minted += 1;
+        Ok((index, item))
+    }
+}
+
+pub fn mint_from_pool(ctx: Context<MintFromPool>) -> Result<()> {
+    let remaining = ctx.accounts.validate_pool()?;
+    let (pool_index, item) = ctx.accounts.draw_item(remaining)?;
+
+    let mut nft_data = NftData {
+        name: item.name,
+        symbol: ctx.accounts.collection_info.symbol.clone(),
+        uri: item.uri,
+        seller_fee_basis_points: Some(ctx.accounts.item_pool.seller_fee_basis_points),
+        creators: Some(ctx.accounts.item_pool.creators.clone()),
+        uses: None,
+    };
+    nft_data.inherit_royalties(&ctx.accounts.collection_info)?;
+    nft_data.validate()?;
+
+    let clock = Clock::get()?;
+
+    let uri = ctx.accounts.collection_info.minted_uri(&nft_data.uri).to_string();
+    let index = ctx.accounts.collection_info.take_next_index()?;
+
+    let authority_bump = ctx.bumps.mint_authority;
+    let seeds = &[&b"authority"[..], &[authority_bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    // The minter is not a creator, so no creator can be verified at mint time.
+    let creators: Vec<Creator> = nft_data.creators()
+        .iter()
+        .map(|creator_data| Creator {
+            address: creator_data.address,
+            verified: false,
+            share: creator_data.share,
+        })
+        .collect();
+
+    let mint_accounts = MasterEditionMintAccounts {
+        payer: &ctx.accounts.owner.to_account_info(),
+        mint: &ctx.accounts.mint.to_account_info(),
+        destination: &ctx.accounts.destination.to_account_info(),
+        metadata: &ctx.accounts.metadata.to_account_info(),
+        master_edition: &ctx.accounts.master_edition.to_account_info(),
+        authority: &ctx.accounts.mint_authority.to_account_info(),
+        system_program: &ctx.accounts.system_program.to_account_info(),
+        token_program: &ctx.accounts.token_program.to_account_info(),
+        token_metadata_program: &ctx.accounts.token_metadata_program.to_account_info(),
+    };
+    mint_accounts.mint(
+        DataV2 {
+            name: nft_data.name.clone(),
+            symbol: nft_data.symbol.clone(),
+            uri: uri.clone(),
+            seller_fee_basis_points: nft_data.seller_fee_basis_points(),
+            creators: Some(creators),
+            collection: Some(Collection {
+                verified: false,
+                key: ctx.accounts.collection_mint.key(),
+            }),
+            uses: None,
+        },
+        signer_seeds,
+    )?;
+    msg!("NFT minted from pool item {}!", pool_index);
+
+    let soulbound = ctx.accounts.soulbound_record.is_some();
+    if soulbound {
+        mint_accounts.freeze_soulbound(signer_seeds)?;
+        msg!("NFT frozen as soulbound!");
+    }
+
+    if let (Some(soulbound_record), Some(bump)) =
+        (ctx.accounts.soulbound_record.as_mut(), ctx.bumps.soulbound_record)
+    {
+        soulbound_record.mint = ctx.accounts.mint.key();
+        soulbound_record.collection_mint = ctx.accounts.collection_mint.key();
+        soulbound_record.bound_at = clock.unix_timestamp;
+        soulbound_record.bump = bump;
+    }
+
+    resize_with_rent(
+        &ctx.accounts.nft_info.to_account_info(),
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        NftInfo::space(&nft_data.name, &nft_data.symbol, &uri),
+    )?;
+
+    let nft_info = &mut ctx.accounts.nft_info;
+    nft_info.mint = ctx.accounts.mint.key();
+    nft_info.collection_mint = ctx.accounts.collection_mint.key();
+    nft_info.name = nft_data.name.clone();
+    nft_info.symbol = nft_data.symbol.clone();
+    nft_info.uri = uri.clone();
+    nft_info.owner = ctx.accounts.owner.key();
+    nft_info.minted_at = clock.unix_timestamp;
+    nft_info.verified = false;
+    nft_info.bump = ctx.bumps.nft_info;
+    nft_info.index = Some(index);
+    nft_info.frozen_since = soulbound.then_some(clock.unix_timestamp);
+
+    emit_cpi!(PoolItemMinted {
+        item_pool: ctx.accounts.item_pool.key(),
+        mint: ctx.accounts.mint.key(),
+        pool_index,
+        remaining: remaining - 1,
+    });
+    emit_cpi!(NftMinted {
+        mint: ctx.accounts.mint.key(),
+        collection_mint: ctx.accounts.collection_mint.key(),
+        name: nft_data.name,
+        symbol: nft_data.symbol,
+        uri,
+        owner: ctx.accounts.owner.key(),
+        minted_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
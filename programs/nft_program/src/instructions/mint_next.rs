@@ -0,0 +1,208 @@
+use super::*;
+use crate::instructions::shared::validation::ValidatableData;
+
+/// Mints the next item of a collection with its name and URI rendered from the collection's
+/// `MintTemplate` and `CollectionInfo.next_index`, instead of uploading the full `NftData`.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MintNext<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        init,
+        payer = owner,
+        mint::decimals = 0,
+        mint::authority = mint_authority,
+        mint::freeze_authority = mint_authority,
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = owner,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub destination: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [b"authority"],
+        bump,
+    )]
+    /// CHECK: This account is used for signing purposes only
+    pub mint_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"collection", collection_mint.key().as_ref()],
+        bump = collection_info.bump,
+    )]
+    pub collection_info: Box<Account<'info, CollectionInfo>>,
+
+    #[account(
+        seeds = [b"mint_template", collection_mint.key().as_ref()],
+        bump = mint_template.bump,
+    )]
+    pub mint_template: Box<Account<'info, MintTemplate>>,
+
+    /// Omit to mint in lite mode. Required while the collection's reveal is pending.
+    #[account(
+        init,
+        payer = owner,
+        space = NftInfo::space(
+            &mint_template.template.render_name(collection_info.next_index),
+            &collection_info.symbol,
+            collection_info.minted_uri(&mint_template.template.render_uri(collection_info.next_index)),
+        ),
+        seeds = [b"nft", mint.key().as_ref()],
+        bump,
+    )]
+    pub nft_info: Option<Box<Account<'info, NftInfo>>>,
+
+    /// Pass to mint a soulbound NFT, frozen in the owner's wallet until `revoke_soulbound`.
+    /// Required when the collection is soulbound.
+    #[account(
+        init,
+        payer = owner,
+        space = SoulboundRecord::SPACE,
+        seeds = [b"soulbound", mint.key().as_ref()],
+        bump,
+    )]
+    pub soulbound_record: Option<Box<Account<'info, SoulboundRecord>>>,
+
+    #[account(mut)]
+    /// CHECK: This account will be initialized by the metaplex program
+    pub metadata: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: This account will be initialized by the metaplex program
+    pub master_edition: UncheckedAccount<'info>,
+
+    pub collection_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
+}
+
+impl<'info> NftUtils for MintNext<'info> {}
+
+impl<'info> MintNext<'info> {
+    /// Renders the next item, checked with the same rules as a `mint_nft` upload.
+    fn render_nft_data(&self) -> Result<NftData> {
+        if self.collection_info.reveal_pending() && self.nft_info.is_none() {
+            return Err(error!(NftError::MissingNftInfo));
+        }
+        self.collection_info.require_soulbound(self.soulbound_record.is_some())?;
+
+        let template = &self.mint_template.template;
+        let index = self.collection_info.next_index;
+        let mut nft_data = NftData {
+            name: template.render_name(index),
+            symbol: self.collection_info.symbol.clone(),
+            uri: template.render_uri(index),
+            seller_fee_basis_points: Some(template.seller_fee_basis_points),
+            creators: Some(template.creators.clone()),
+            uses: None,
+        };
+        nft_data.inherit_royalties(&self.collection_info)?;
+        nft_data.validate()?;
+        Ok(nft_data)
+    }
+}
+
+pub fn mint_next(ctx: Context<MintNext>) -> Result<()> {
+    let nft_data = ctx.accounts.render_nft_data()?;
+
+    let clock = Clock::get()?;
+
+    let uri = ctx.accounts.collection_info.minted_uri(&nft_data.uri).to_string();
+    let index = ctx.accounts.collection_info.take_next_index()?;
+
+    let authority_bump = ctx.bumps.mint_authority;
+    let seeds = &[&b"authority"[..], &[authority_bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    // The minter is not a creator, so no creator can be verified at mint time.
+    let creators: Vec<Creator> = nft_data.creators()
+        .iter()
+        .map(|creator_data| Creator {
+            address: creator_data.address,
+            verified: false,
+            share: creator_data.share,
+        })
+        .collect();
+
+    let mint_accounts = MasterEditionMintAccounts {
+        payer: &ctx.accounts.owner.to_account_info(),
+        mint: &ctx.accounts.mint.to_account_info(),
+        destination: &ctx.accounts.destination.to_account_info(),
+        metadata: &ctx.accounts.metadata.to_account_info(),
+        master_edition: &ctx.accounts.master_edition.to_account_info(),
+        authority: &ctx.accounts.mint_authority.to_account_info(),
+        system_program: &ctx.accounts.system_program.to_account_info(),
+        token_program: &ctx.accounts.token_program.to_account_info(),
+        token_metadata_program: &ctx.accounts.token_metadata_program.to_account_info(),
+    };
+    mint_accounts.mint(
+        DataV2 {
+            name: nft_data.name.clone(),
+            symbol: nft_data.symbol.clone(),
+            uri: uri.clone(),
+            seller_fee_basis_points: nft_data.seller_fee_basis_points(),
+            creators: Some(creators),
+            collection: Some(Collection {
+                verified: false,
+                key: ctx.accounts.collection_mint.key(),
+            }),
+            uses: None,
+        },
+        signer_seeds,
+    )?;
+    msg!("NFT #{} minted!", index);
+
+    let soulbound = ctx.accounts.soulbound_record.is_some();
+    if soulbound {
+        mint_accounts.freeze_soulbound(signer_seeds)?;
+        msg!("NFT frozen as soulbound!");
+    }
+
+    if let (Some(soulbound_record), Some(bump)) =
+        (ctx.accounts.soulbound_record.as_mut(), ctx.bumps.soulbound_record)
+    {
+        soulbound_record.mint = ctx.accounts.mint.key();
+        soulbound_record.collection_mint = ctx.accounts.collection_mint.key();
+        soulbound_record.bound_at = clock.unix_timestamp;
+        soulbound_record.bump = bump;
+    }
+
+    if let (Some(nft_info), Some(bump)) = (ctx.accounts.nft_info.as_mut(), ctx.bumps.nft_info) {
+        nft_info.mint = ctx.accounts.mint.key();
+        nft_info.collection_mint = ctx.accounts.collection_mint.key();
+        nft_info.name = nft_data.name.clone();
+        nft_info.symbol = nft_data.symbol.clone();
+        nft_info.uri = uri.clone();
+        nft_info.owner = ctx.accounts.owner.key();
+        nft_info.minted_at = clock.unix_timestamp;
+        nft_info.verified = false;
+        nft_info.bump = bump;
+        nft_info.index = Some(index);
+        nft_info.frozen_since = soulbound.then_some(clock.unix_timestamp);
+    }
+
+    emit_cpi!(NftMinted {
+        mint: ctx.accounts.mint.key(),
+        collection_mint: ctx.accounts.collection_mint.key(),
+        name: nft_data.name,
+        symbol: nft_data.symbol,
+        uri,
+        owner: ctx.accounts.owner.key(),
+        minted_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -6,8 +6,27 @@ pub struct NftData {
     pub name: String,
     pub symbol: String,
     pub uri: String,
-    pub seller_fee_basis_points: u16,
-    pub creators: Vec<CreatorData>,
+    /// Left out to inherit the collection's default, see `CollectionInfo::apply_royalty_policy`.
+    pub seller_fee_basis_points: Option<u16>,
+    /// Left out to inherit the collection's default creators.
+    pub creators: Option<Vec<CreatorData>>,
+    /// Makes the NFT consumable through `use_nft`, e.g. a ticket.
+    pub uses: Option<UsesData>,
+}
+
+impl NftData {
+    /// Applies the collection's royalty policy, after which both royalty fields are set.
+    pub fn inherit_royalties(&mut self, collection_info: &CollectionInfo) -> Result<()> {
+        collection_info.apply_royalty_policy(&mut self.seller_fee_basis_points, &mut self.creators)
+    }
+
+    /// For mints that cannot track uses, which need an `NftInfo` of a Token Metadata NFT.
+    pub fn reject_uses(&self) -> Result<()> {
+        if self.uses.is_some() {
+            return Err(error!(NftError::UsesNotSupported));
+        }
+        Ok(())
+    }
 }
 
 #[event_cpi]
@@ -23,16 +42,18 @@ pub struct MintNFT<'info> {
         mint::decimals = 0,
         mint::authority = mint_authority,
         mint::freeze_authority = mint_authority,
+        mint::token_program = token_program,
     )]
-    pub mint: Account<'info, Mint>,
+    pub mint: InterfaceAccount<'info, Mint>,
     
     #[account(
         init,
         payer = owner,
         associated_token::mint = mint,
-        associated_token::authority = owner
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
     )]
-    pub destination: Account<'info, TokenAccount>,
+    pub destination: InterfaceAccount<'info, TokenAccount>,
     
     #[account(
         seeds = [b"authority"],
@@ -41,14 +62,34 @@ pub struct MintNFT<'info> {
     /// CHECK: This account is used for signing purposes only
     pub mint_authority: UncheckedAccount<'info>,
     
+    #[account(
+        mut,
+        seeds = [b"collection", collection_mint.key().as_ref()],
+        bump = collection_info.bump,
+    )]
+    pub collection_info: Box<Account<'info, CollectionInfo>>,
+
+    /// Omit to mint in lite mode, leaving Token Metadata as the only record of the NFT.
+    /// Required while the collection's reveal is pending and for NFTs with `uses`.
     #[account(
         init,
         payer = owner,
-        space = 8 + NftInfo::INIT_SPACE,
+        space = NftInfo::space(&nft_data.name, &nft_data.symbol, collection_info.minted_uri(&nft_data.uri)),
         seeds = [b"nft", mint.key().as_ref()],
         bump,
     )]
-    pub nft_info: Account<'info, NftInfo>,
+    pub nft_info: Option<Account<'info, NftInfo>>,
+
+    /// Pass to mint a soulbound NFT, frozen in the owner's wallet until `revoke_soulbound`.
+    /// Required when the collection is soulbound.
+    #[account(
+        init,
+        payer = owner,
+        space = SoulboundRecord::SPACE,
+        seeds = [b"soulbound", mint.key().as_ref()],
+        bump,
+    )]
+    pub soulbound_record: Option<Box<Account<'info, SoulboundRecord>>>,
     
     #[account(mut)]
     /// CHECK: This account will be initialized by the metaplex program
@@ -59,10 +100,10 @@ pub struct MintNFT<'info> {
     pub master_edition: UncheckedAccount<'info>,
     
     #[account(mut)]
-    pub collection_mint: Account<'info, Mint>,
+    pub collection_mint: InterfaceAccount<'info, Mint>,
     
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_metadata_program: Program<'info, Metadata>,
 }
@@ -70,32 +111,65 @@ pub struct MintNFT<'info> {
 impl validation::ValidatableData for NftData {
     fn name(&self) -> &str { &self.name }
     fn symbol(&self) -> &str { &self.symbol }
-    fn seller_fee_basis_points(&self) -> u16 { self.seller_fee_basis_points }
-    fn creators(&self) -> &[CreatorData] { &self.creators }
+    fn uri(&self) -> &str { &self.uri }
+    fn seller_fee_basis_points(&self) -> u16 { self.seller_fee_basis_points.unwrap_or_default() }
+    fn creators(&self) -> &[CreatorData] { self.creators.as_deref().unwrap_or_default() }
+
+    /// Royalties left out are checked once inherited, as part of the collection defaults.
+    fn validate(&self) -> Result<()> {
+        validation::validate_name(&self.name)?;
+        validation::validate_symbol(&self.symbol)?;
+        validation::validate_uri(&self.uri)?;
+        if let Some(fee) = self.seller_fee_basis_points {
+            validation::validate_seller_fee_basis_points(fee)?;
+        }
+        if let Some(creators) = &self.creators {
+            validation::validate_creators(creators)?;
+        }
+        if let Some(uses) = &self.uses {
+            uses.validate()?;
+        }
+        Ok(())
+    }
 }
 
 impl<'info> NftUtils for MintNFT<'info> {}
 
 impl<'info> MintNFT<'info> {
     fn validate_nft_data(&self, nft_data: &NftData) -> Result<()> {
+        if self.collection_info.standard != CollectionStandard::Metaplex {
+            return Err(error!(NftError::UnsupportedCollectionStandard));
+        }
+        let needs_nft_info = self.collection_info.reveal_pending() || nft_data.uses.is_some();
+        if needs_nft_info && self.nft_info.is_none() {
+            return Err(error!(NftError::MissingNftInfo));
+        }
+        self.collection_info.require_soulbound(self.soulbound_record.is_some())?;
+        // `use_nft` rejects soulbound NFTs, so their uses could never be consumed.
+        if nft_data.uses.is_some() && self.soulbound_record.is_some() {
+            return Err(error!(NftError::NftSoulbound));
+        }
         nft_data.validate()
     }
 }
 
 pub fn mint_nft(
     ctx: Context<MintNFT>,
-    nft_data: NftData,
+    mut nft_data: NftData,
 ) -> Result<()> {
+    nft_data.inherit_royalties(&ctx.accounts.collection_info)?;
     ctx.accounts.validate_nft_data(&nft_data)?;
     
     let clock = Clock::get()?;
 
-    let metadata = &ctx.accounts.metadata.to_account_info();
+    // Items of a collection with a pending reveal carry the placeholder until `reveal`.
+    let uri = ctx.accounts.collection_info.minted_uri(&nft_data.uri).to_string();
+    let index = ctx.accounts.collection_info.take_next_index()?;
+
     let master_edition = &ctx.accounts.master_edition.to_account_info();
     let mint = &ctx.accounts.mint.to_account_info();
     let authority = &ctx.accounts.mint_authority.to_account_info();
     let payer = &ctx.accounts.owner.to_account_info();
-    let system_program = &ctx.accounts.system_program.to_account_info();
     let spl_token_program = &ctx.accounts.token_program.to_account_info();
     let spl_metadata_program = &ctx.accounts.token_metadata_program.to_account_info();
 
@@ -103,18 +177,8 @@ pub fn mint_nft(
     let seeds = &[&b"authority"[..], &[authority_bump]];
     let signer_seeds = &[&seeds[..]];
 
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_accounts = MintTo {
-        mint: ctx.accounts.mint.to_account_info(),
-        to: ctx.accounts.destination.to_account_info(),
-        authority: ctx.accounts.mint_authority.to_account_info(),
-    };
-    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-    mint_to(cpi_ctx, 1).map_err(|_| NftError::InvalidName)?;
-    msg!("NFT minted!");
-    
     // Convert CreatorData to Creator
-    let creators: Vec<Creator> = nft_data.creators
+    let creators: Vec<Creator> = nft_data.creators()
         .iter()
         .map(|creator_data| Creator {
             address: creator_data.address,
@@ -123,82 +187,72 @@ pub fn mint_nft(
         })
         .collect();
 
-    let metadata_account = CreateMetadataAccountV3Cpi::new(
-        spl_metadata_program,
-        CreateMetadataAccountV3CpiAccounts {
-            metadata,
-            mint,
-            mint_authority: authority,
-            payer,
-            update_authority: (authority, true),
-            system_program,
-            rent: None,
-        },
-        CreateMetadataAccountV3InstructionArgs {
-            data: DataV2 {
-                name: nft_data.name.clone(),
-                symbol: nft_data.symbol.clone(),
-                uri: nft_data.uri.clone(),
-                seller_fee_basis_points: nft_data.seller_fee_basis_points,
-                creators: Some(creators),
-                collection: Some(Collection {
-                    verified: false,
-                    key: ctx.accounts.collection_mint.key(),
-                }),
-                uses: None,
-            },
-            is_mutable: true,
-            collection_details: None,
-        },
-    );
-    metadata_account
-        .invoke_signed(signer_seeds)
-        .map_err(|_| NftError::InvalidUri)?;
-    msg!("NFT Metadata Account created!");
-
-    let master_edition_account = CreateMasterEditionV3Cpi::new(
-        spl_metadata_program,
-        CreateMasterEditionV3CpiAccounts {
-            edition: master_edition,
-            update_authority: authority,
-            mint_authority: authority,
-            mint,
-            payer,
-            metadata,
-            token_program: spl_token_program,
-            system_program,
-            rent: None,
-        },
-        CreateMasterEditionV3InstructionArgs {
-            max_supply: Some(0),
+    let mint_accounts = MasterEditionMintAccounts {
+        payer,
+        mint,
+        destination: &ctx.accounts.destination.to_account_info(),
+        metadata: &ctx.accounts.metadata.to_account_info(),
+        master_edition,
+        authority,
+        system_program: &ctx.accounts.system_program.to_account_info(),
+        token_program: spl_token_program,
+        token_metadata_program: spl_metadata_program,
+    };
+    mint_accounts.mint(
+        DataV2 {
+            name: nft_data.name.clone(),
+            symbol: nft_data.symbol.clone(),
+            uri: uri.clone(),
+            seller_fee_basis_points: nft_data.seller_fee_basis_points(),
+            creators: Some(creators),
+            collection: Some(Collection {
+                verified: false,
+                key: ctx.accounts.collection_mint.key(),
+            }),
+            // Tracked in `NftInfo.remaining_uses` only, Token Metadata's copy would go stale.
+            uses: None,
         },
-    );
-    master_edition_account
-        .invoke_signed(signer_seeds)
-        .map_err(|_| NftError::InvalidUri)?;
-    msg!("NFT Master Edition Account created");
-
-    let name_bytes = ctx.accounts.string_to_bytes::<32>(&nft_data.name);
-    let symbol_bytes = ctx.accounts.string_to_bytes::<10>(&nft_data.symbol);
-    let uri_bytes = ctx.accounts.string_to_bytes::<200>(&nft_data.uri);
-    
-    let nft_info = &mut ctx.accounts.nft_info;
-    nft_info.mint = ctx.accounts.mint.key();
-    nft_info.collection_mint = ctx.accounts.collection_mint.key();
-    nft_info.name = name_bytes;         
-    nft_info.symbol = symbol_bytes;      
-    nft_info.uri = uri_bytes;         
-    nft_info.owner = ctx.accounts.owner.key();
-    nft_info.minted_at = clock.unix_timestamp;
-    nft_info.verified = false;  
-    nft_info.bump = ctx.bumps.nft_info;
+        signer_seeds,
+    )?;
+    msg!("NFT minted!");
+
+    let soulbound = ctx.accounts.soulbound_record.is_some();
+    if soulbound {
+        mint_accounts.freeze_soulbound(signer_seeds)?;
+        msg!("NFT frozen as soulbound!");
+    }
+
+    if let (Some(soulbound_record), Some(bump)) =
+        (ctx.accounts.soulbound_record.as_mut(), ctx.bumps.soulbound_record)
+    {
+        soulbound_record.mint = ctx.accounts.mint.key();
+        soulbound_record.collection_mint = ctx.accounts.collection_mint.key();
+        soulbound_record.bound_at = clock.unix_timestamp;
+        soulbound_record.bump = bump;
+    }
+
+    if let (Some(nft_info), Some(bump)) = (ctx.accounts.nft_info.as_mut(), ctx.bumps.nft_info) {
+        nft_info.mint = ctx.accounts.mint.key();
+        nft_info.collection_mint = ctx.accounts.collection_mint.key();
+        nft_info.name = nft_data.name.clone();
+        nft_info.symbol = nft_data.symbol.clone();
+        nft_info.uri = uri.clone();
+        nft_info.owner = ctx.accounts.owner.key();
+        nft_info.minted_at = clock.unix_timestamp;
+        nft_info.verified = false;
+        nft_info.bump = bump;
+        nft_info.index = Some(index);
+        nft_info.uses = nft_data.uses;
+        nft_info.remaining_uses = nft_data.uses.map_or(0, |uses| uses.total);
+        nft_info.frozen_since = soulbound.then_some(clock.unix_timestamp);
+    }
 
     emit_cpi!(NftMinted {
         mint: ctx.accounts.mint.key(),
         collection_mint: ctx.accounts.collection_mint.key(),
         name: nft_data.name,
         symbol: nft_data.symbol,
-        uri: nft_data.uri,
+        uri,
         owner: ctx.accounts.owner.key(),
         minted_at: clock.unix_timestamp,
     });
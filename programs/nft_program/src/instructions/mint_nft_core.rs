@@ -0,0 +1,133 @@
+use super::*;
+use crate::instructions::shared::validation::ValidatableData;
+
+/// Mints a Metaplex Core asset straight into a Core collection. The asset address takes the
+/// place of the NFT mint in `NftInfo`.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(nft_data: NftData)]
+pub struct MintNFTCore<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// Fresh keypair, initialized by the Metaplex Core program.
+    #[account(mut)]
+    pub asset: Signer<'info>,
+
+    #[account(
+        seeds = [b"authority"],
+        bump,
+    )]
+    /// CHECK: This account is used for signing purposes only
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// Omit to mint in lite mode, leaving the Core asset as the only record of the NFT.
+    #[account(
+        init,
+        payer = owner,
+        space = NftInfo::space(&nft_data.name, &nft_data.symbol, &nft_data.uri),
+        seeds = [b"nft", asset.key().as_ref()],
+        bump,
+    )]
+    pub nft_info: Option<Account<'info, NftInfo>>,
+
+    #[account(
+        mut,
+        owner = MPL_CORE_ID,
+    )]
+    /// CHECK: Core collection, owned by the Metaplex Core program and tied to `collection_info`
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"collection", collection.key().as_ref()],
+        bump = collection_info.bump,
+    )]
+    pub collection_info: Box<Account<'info, CollectionInfo>>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(address = MPL_CORE_ID)]
+    /// CHECK: Metaplex Core program, checked with an address constraint
+    pub mpl_core_program: UncheckedAccount<'info>,
+}
+
+impl<'info> MintNFTCore<'info> {
+    fn validate_nft_data(&self, nft_data: &NftData) -> Result<()> {
+        nft_data.reject_uses()?;
+        if self.collection_info.standard != CollectionStandard::Core {
+            return Err(error!(NftError::UnsupportedCollectionStandard));
+        }
+        nft_data.validate()
+    }
+}
+
+pub fn mint_nft_core(
+    ctx: Context<MintNFTCore>,
+    mut nft_data: NftData,
+) -> Result<()> {
+    nft_data.inherit_royalties(&ctx.accounts.collection_info)?;
+    ctx.accounts.validate_nft_data(&nft_data)?;
+
+    let clock = Clock::get()?;
+
+    let authority_bump = ctx.bumps.mint_authority;
+    let seeds = &[&b"authority"[..], &[authority_bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    CoreAccounts {
+        mpl_core_program: &ctx.accounts.mpl_core_program.to_account_info(),
+        payer: &ctx.accounts.owner.to_account_info(),
+        authority: &ctx.accounts.mint_authority.to_account_info(),
+        system_program: &ctx.accounts.system_program.to_account_info(),
+    }
+    .create_asset(
+        &ctx.accounts.asset.to_account_info(),
+        &ctx.accounts.collection.to_account_info(),
+        &ctx.accounts.owner.to_account_info(),
+        signer_seeds,
+        nft_data.name.clone(),
+        nft_data.uri.clone(),
+        nft_data.seller_fee_basis_points(),
+        nft_data.creators(),
+    )?;
+    msg!("Core asset minted!");
+
+    if let (Some(nft_info), Some(bump)) = (ctx.accounts.nft_info.as_mut(), ctx.bumps.nft_info) {
+        nft_info.mint = ctx.accounts.asset.key();
+        nft_info.collection_mint = ctx.accounts.collection.key();
+        nft_info.name = nft_data.name.clone();
+        nft_info.symbol = nft_data.symbol.clone();
+        nft_info.uri = nft_data.uri.clone();
+        nft_info.owner = ctx.accounts.owner.key();
+        nft_info.minted_at = clock.unix_timestamp;
+        // The collection authority signs the asset into the collection, there is no separate verify step.
+        nft_info.verified = true;
+        nft_info.bump = bump;
+    }
+
+    let collection_info = &mut ctx.accounts.collection_info;
+    collection_info.number_of_nfts = collection_info
+        .number_of_nfts
+        .checked_add(1)
+        .ok_or(NftError::NumericalOverflow)?;
+
+    emit_cpi!(NftMinted {
+        mint: ctx.accounts.asset.key(),
+        collection_mint: ctx.accounts.collection.key(),
+        name: nft_data.name,
+        symbol: nft_data.symbol,
+        uri: nft_data.uri,
+        owner: ctx.accounts.owner.key(),
+        minted_at: clock.unix_timestamp,
+    });
+
+    emit_cpi!(CollectionVerified {
+        nft_mint: ctx.accounts.asset.key(),
+        collection_mint: ctx.accounts.collection.key(),
+        authority: ctx.accounts.mint_authority.key(),
+        verified_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,200 @@
+use super::*;
+use crate::instructions::shared::validation::ValidatableData;
+
+pub const COLLECTION_FIELD: &str = "collection";
+pub const SELLER_FEE_BASIS_POINTS_FIELD: &str = "seller_fee_basis_points";
+pub const CREATORS_FIELD: &str = "creators";
+
+/// The royalty fields of a token-metadata extension. Creators are written as `;` separated
+/// `ADDRESS:SHARE` pairs and left out when there are none; Token-2022 has no creator verification.
+pub fn royalty_fields(seller_fee_basis_points: u16, creators: &[CreatorData]) -> Vec<(String, String)> {
+    let mut fields = vec![(SELLER_FEE_BASIS_POINTS_FIELD.to_string(), seller_fee_basis_points.to_string())];
+    if !creators.is_empty() {
+        let creators = creators
+            .iter()
+            .map(|creator| format!("{}:{}", creator.address, creator.share))
+            .collect::<Vec<_>>()
+            .join(";");
+        fields.push((CREATORS_FIELD.to_string(), creators));
+    }
+    fields
+}
+
+/// Mints a Token-2022 NFT whose metadata lives in the mint itself through the
+/// metadata-pointer and token-metadata extensions, without any Metaplex accounts.
+/// The NFT joins its collection as a token-group member.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(nft_data: NftData)]
+pub struct MintNFTToken2022<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        init,
+        payer = owner,
+        mint::decimals = 0,
+        mint::authority = mint_authority,
+        mint::freeze_authority = mint_authority,
+        mint::token_program = token_program,
+        extensions::metadata_pointer::authority = mint_authority,
+        extensions::metadata_pointer::metadata_address = mint,
+        extensions::group_member_pointer::authority = mint_authority,
+        extensions::group_member_pointer::member_address = mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = owner,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub destination: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [b"authority"],
+        bump,
+    )]
+    /// CHECK: This account is used for signing purposes only
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// Omit to mint in lite mode, leaving the mint's token metadata as the only record of the NFT.
+    #[account(
+        init,
+        payer = owner,
+        space = NftInfo::space(&nft_data.name, &nft_data.symbol, &nft_data.uri),
+        seeds = [b"nft", mint.key().as_ref()],
+        bump,
+    )]
+    pub nft_info: Option<Account<'info, NftInfo>>,
+
+    #[account(
+        mut,
+        mint::token_program = token_program,
+    )]
+    pub collection_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [b"collection", collection_mint.key().as_ref()],
+        bump = collection_info.bump,
+    )]
+    pub collection_info: Box<Account<'info, CollectionInfo>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> MintNFTToken2022<'info> {
+    fn validate_nft_data(&self, nft_data: &NftData) -> Result<()> {
+        nft_data.reject_uses()?;
+        if self.collection_info.standard != CollectionStandard::Token2022Group {
+            return Err(error!(NftError::UnsupportedCollectionStandard));
+        }
+        nft_data.validate()
+    }
+}
+
+pub fn mint_nft_token_2022(
+    ctx: Context<MintNFTToken2022>,
+    mut nft_data: NftData,
+) -> Result<()> {
+    nft_data.inherit_royalties(&ctx.accounts.collection_info)?;
+    ctx.accounts.validate_nft_data(&nft_data)?;
+
+    let clock = Clock::get()?;
+
+    let payer = &ctx.accounts.owner.to_account_info();
+    let mint = &ctx.accounts.mint.to_account_info();
+    let authority = &ctx.accounts.mint_authority.to_account_info();
+    let collection_mint = &ctx.accounts.collection_mint.to_account_info();
+    let token_program = &ctx.accounts.token_program.to_account_info();
+    let system_program = &ctx.accounts.system_program.to_account_info();
+
+    let authority_bump = ctx.bumps.mint_authority;
+    let seeds = &[&b"authority"[..], &[authority_bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    let token_2022 = Token2022MintAccounts {
+        payer,
+        mint,
+        authority,
+        token_program,
+        system_program,
+    };
+
+    let mut additional_metadata = vec![(COLLECTION_FIELD.to_string(), collection_mint.key().to_string())];
+    additional_metadata.extend(royalty_fields(nft_data.seller_fee_basis_points(), nft_data.creators()));
+    token_2022.initialize_token_metadata(
+        signer_seeds,
+        nft_data.name.clone(),
+        nft_data.symbol.clone(),
+        nft_data.uri.clone(),
+        additional_metadata,
+    )?;
+    msg!("NFT Token Metadata initialized!");
+
+    fund_account_growth(
+        mint,
+        payer,
+        system_program,
+        mint_extension_tlv_len(ExtensionType::TokenGroupMember)?,
+    )?;
+    token_member_initialize(CpiContext::new_with_signer(
+        token_program.clone(),
+        TokenMemberInitialize {
+            program_id: token_program.clone(),
+            member: mint.clone(),
+            member_mint: mint.clone(),
+            member_mint_authority: authority.clone(),
+            group: collection_mint.clone(),
+            group_update_authority: authority.clone(),
+        },
+        signer_seeds,
+    ))?;
+    msg!("NFT added to collection group!");
+
+    token_2022.mint_one_and_lock(&ctx.accounts.destination.to_account_info(), signer_seeds)?;
+    msg!("NFT minted!");
+
+    if let (Some(nft_info), Some(bump)) = (ctx.accounts.nft_info.as_mut(), ctx.bumps.nft_info) {
+        nft_info.mint = ctx.accounts.mint.key();
+        nft_info.collection_mint = ctx.accounts.collection_mint.key();
+        nft_info.name = nft_data.name.clone();
+        nft_info.symbol = nft_data.symbol.clone();
+        nft_info.uri = nft_data.uri.clone();
+        nft_info.owner = ctx.accounts.owner.key();
+        nft_info.minted_at = clock.unix_timestamp;
+        // Group membership is established by this instruction, there is no separate verify step.
+        nft_info.verified = true;
+        nft_info.bump = bump;
+    }
+
+    let collection_info = &mut ctx.accounts.collection_info;
+    collection_info.number_of_nfts = collection_info
+        .number_of_nfts
+        .checked_add(1)
+        .ok_or(NftError::NumericalOverflow)?;
+
+    emit_cpi!(NftMinted {
+        mint: ctx.accounts.mint.key(),
+        collection_mint: ctx.accounts.collection_mint.key(),
+        name: nft_data.name,
+        symbol: nft_data.symbol,
+        uri: nft_data.uri,
+        owner: ctx.accounts.owner.key(),
+        minted_at: clock.unix_timestamp,
+    });
+
+    emit_cpi!(CollectionVerified {
+        nft_mint: ctx.accounts.mint.key(),
+        collection_mint: ctx.accounts.collection_mint.key(),
+        authority: ctx.accounts.mint_authority.key(),
+        verified_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
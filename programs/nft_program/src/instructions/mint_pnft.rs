@@ -0,0 +1,224 @@
+use super::*;
+use crate::instructions::shared::validation::ValidatableData;
+
+/// Mints a `ProgrammableNonFungible` through Token Metadata's `Create`/`Mint` V1 instructions so
+/// royalties and transfer rules are enforced by Token Metadata and the optional rule set.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(nft_data: NftData)]
+pub struct MintPNFT<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// Created and initialized by Token Metadata's `Create` instruction.
+    #[account(mut)]
+    pub mint: Signer<'info>,
+
+    #[account(mut)]
+    /// CHECK: Associated token account created by Token Metadata's `Mint` instruction
+    pub destination: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"authority"],
+        bump,
+    )]
+    /// CHECK: This account is used for signing purposes only
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// Omit to mint in lite mode, leaving Token Metadata as the only record of the NFT.
+    /// Required while the collection's reveal is pending.
+    #[account(
+        init,
+        payer = owner,
+        space = NftInfo::space(&nft_data.name, &nft_data.symbol, collection_info.minted_uri(&nft_data.uri)),
+        seeds = [b"nft", mint.key().as_ref()],
+        bump,
+    )]
+    pub nft_info: Option<Account<'info, NftInfo>>,
+
+    #[account(mut)]
+    /// CHECK: This account will be initialized by the metaplex program
+    pub metadata: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: This account will be initialized by the metaplex program
+    pub master_edition: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: This account will be initialized by the metaplex program
+    pub token_record: UncheckedAccount<'info>,
+
+    pub collection_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [b"collection", collection_mint.key().as_ref()],
+        bump = collection_info.bump,
+    )]
+    pub collection_info: Box<Account<'info, CollectionInfo>>,
+
+    /// CHECK: Rule set applied to the pNFT, validated by the metaplex program
+    pub authorization_rules: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Token Auth Rules program, validated by the metaplex program
+    pub authorization_rules_program: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(address = INSTRUCTIONS_ID)]
+    /// CHECK: Sysvar instruction account that is being checked with an address constraint
+    pub sysvar_instruction: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
+}
+
+impl<'info> MintPNFT<'info> {
+    fn validate_nft_data(&self, nft_data: &NftData) -> Result<()> {
+        if self.collection_info.standard != CollectionStandard::Metaplex {
+            return Err(error!(NftError::UnsupportedCollectionStandard));
+        }
+        nft_data.reject_uses()?;
+        // pNFTs cannot be frozen as soulbound, Token Metadata holds their freeze.
+        self.collection_info.require_soulbound(false)?;
+        if self.collection_info.reveal_pending() && self.nft_info.is_none() {
+            return Err(error!(NftError::MissingNftInfo));
+        }
+        nft_data.validate()
+    }
+}
+
+pub fn mint_pnft(
+    ctx: Context<MintPNFT>,
+    mut nft_data: NftData,
+) -> Result<()> {
+    nft_data.inherit_royalties(&ctx.accounts.collection_info)?;
+    ctx.accounts.validate_nft_data(&nft_data)?;
+
+    let clock = Clock::get()?;
+
+    // Items of a collection with a pending reveal carry the placeholder until `reveal`.
+    let uri = ctx.accounts.collection_info.minted_uri(&nft_data.uri).to_string();
+    let index = ctx.accounts.collection_info.take_next_index()?;
+
+    let metadata = &ctx.accounts.metadata.to_account_info();
+    let master_edition = &ctx.accounts.master_edition.to_account_info();
+    let token_record = &ctx.accounts.token_record.to_account_info();
+    let mint = &ctx.accounts.mint.to_account_info();
+    let destination = &ctx.accounts.destination.to_account_info();
+    let owner = &ctx.accounts.owner.to_account_info();
+    let authority = &ctx.accounts.mint_authority.to_account_info();
+    let system_program = &ctx.accounts.system_program.to_account_info();
+    let sysvar_instructions = &ctx.accounts.sysvar_instruction.to_account_info();
+    let spl_token_program = &ctx.accounts.token_program.to_account_info();
+    let spl_ata_program = &ctx.accounts.associated_token_program.to_account_info();
+    let spl_metadata_program = &ctx.accounts.token_metadata_program.to_account_info();
+    let authorization_rules = ctx.accounts.authorization_rules.as_ref().map(|rules| rules.to_account_info());
+    let authorization_rules_program = ctx
+        .accounts
+        .authorization_rules_program
+        .as_ref()
+        .map(|program| program.to_account_info());
+
+    let authority_bump = ctx.bumps.mint_authority;
+    let seeds = &[&b"authority"[..], &[authority_bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    let creators: Vec<Creator> = nft_data.creators()
+        .iter()
+        .map(|creator_data| Creator {
+            address: creator_data.address,
+            verified: creator_data.verified,
+            share: creator_data.share,
+        })
+        .collect();
+
+    CreateV1Cpi::new(
+        spl_metadata_program,
+        CreateV1CpiAccounts {
+            metadata,
+            master_edition: Some(master_edition),
+            mint: (mint, true),
+            authority,
+            payer: owner,
+            update_authority: (authority, true),
+            system_program,
+            sysvar_instructions,
+            spl_token_program: Some(spl_token_program),
+        },
+        CreateV1InstructionArgs {
+            name: nft_data.name.clone(),
+            symbol: nft_data.symbol.clone(),
+            uri: uri.clone(),
+            seller_fee_basis_points: nft_data.seller_fee_basis_points(),
+            creators: Some(creators),
+            primary_sale_happened: false,
+            is_mutable: true,
+            token_standard: TokenStandard::ProgrammableNonFungible,
+            collection: Some(Collection {
+                verified: false,
+                key: ctx.accounts.collection_mint.key(),
+            }),
+            uses: None,
+            collection_details: None,
+            rule_set: authorization_rules.as_ref().map(|rules| rules.key()),
+            decimals: Some(0),
+            print_supply: Some(PrintSupply::Zero),
+        },
+    )
+    .invoke_signed(signer_seeds)?;
+    msg!("pNFT Metadata and Master Edition created!");
+
+    MintV1Cpi::new(
+        spl_metadata_program,
+        MintV1CpiAccounts {
+            token: destination,
+            token_owner: Some(owner),
+            metadata,
+            master_edition: Some(master_edition),
+            token_record: Some(token_record),
+            mint,
+            authority,
+            delegate_record: None,
+            payer: owner,
+            system_program,
+            sysvar_instructions,
+            spl_token_program,
+            spl_ata_program,
+            authorization_rules_program: authorization_rules_program.as_ref(),
+            authorization_rules: authorization_rules.as_ref(),
+        },
+        MintV1InstructionArgs {
+            amount: 1,
+            authorization_data: None,
+        },
+    )
+    .invoke_signed(signer_seeds)?;
+    msg!("pNFT minted!");
+
+    if let (Some(nft_info), Some(bump)) = (ctx.accounts.nft_info.as_mut(), ctx.bumps.nft_info) {
+        nft_info.mint = ctx.accounts.mint.key();
+        nft_info.collection_mint = ctx.accounts.collection_mint.key();
+        nft_info.name = nft_data.name.clone();
+        nft_info.symbol = nft_data.symbol.clone();
+        nft_info.uri = uri.clone();
+        nft_info.owner = ctx.accounts.owner.key();
+        nft_info.minted_at = clock.unix_timestamp;
+        nft_info.verified = false;
+        nft_info.bump = bump;
+        nft_info.index = Some(index);
+    }
+
+    emit_cpi!(NftMinted {
+        mint: ctx.accounts.mint.key(),
+        collection_mint: ctx.accounts.collection_mint.key(),
+        name: nft_data.name,
+        symbol: nft_data.symbol,
+        uri,
+        owner: ctx.accounts.owner.key(),
+        minted_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,11 +1,95 @@
 use super::*;
 
 pub mod mint_nft;
+pub mod mint_nft_token_2022;
+pub mod mint_pnft;
+pub mod mint_nft_core;
+pub mod mint_compressed_nft;
 pub mod create_collection;
+pub mod create_collection_token_2022;
+pub mod create_collection_core;
+pub mod create_compressed_tree;
 pub mod verify_collection;
+pub mod unverify_collection;
+pub mod reconcile_collection_size;
+pub mod set_royalty_policy;
+pub mod set_soulbound_policy;
+pub mod transfer_nft;
+pub mod burn_nft;
+pub mod use_nft;
+pub mod set_use_authority;
+pub mod revoke_soulbound;
+pub mod freeze_nft;
+pub mod create_staking_pool;
+pub mod stake_nft;
+pub mod unstake_nft;
+pub mod claim_rewards;
+pub mod rent_out;
+pub mod reclaim;
+pub mod list_nft;
+pub mod cancel_listing;
+pub mod buy_nft;
+pub mod place_bid;
+pub mod cancel_bid;
+pub mod accept_bid;
+pub mod create_auction;
+pub mod place_auction_bid;
+pub mod settle_auction;
+pub mod configure_reveal;
+pub mod reveal;
+pub mod create_item_pool;
+pub mod add_pool_items;
+pub mod mint_from_pool;
+pub mod set_mint_template;
+pub mod mint_next;
+pub mod migrate_accounts;
+pub mod bubblegum;
+pub mod mpl_core;
 pub mod shared;
 
 pub use mint_nft::*;
+pub use mint_nft_token_2022::*;
+pub use mint_pnft::*;
+pub use mint_nft_core::*;
+pub use mint_compressed_nft::*;
 pub use create_collection::*;
+pub use create_collection_token_2022::*;
+pub use create_collection_core::*;
+pub use create_compressed_tree::*;
 pub use verify_collection::*;
+pub use unverify_collection::*;
+pub use reconcile_collection_size::*;
+pub use set_royalty_policy::*;
+pub use set_soulbound_policy::*;
+pub use transfer_nft::*;
+pub use burn_nft::*;
+pub use use_nft::*;
+pub use set_use_authority::*;
+pub use revoke_soulbound::*;
+pub use freeze_nft::*;
+pub use create_staking_pool::*;
+pub use stake_nft::*;
+pub use unstake_nft::*;
+pub use claim_rewards::*;
+pub use rent_out::*;
+pub use reclaim::*;
+pub use list_nft::*;
+pub use cancel_listing::*;
+pub use buy_nft::*;
+pub use place_bid::*;
+pub use cancel_bid::*;
+pub use accept_bid::*;
+pub use create_auction::*;
+pub use place_auction_bid::*;
+pub use settle_auction::*;
+pub use configure_reveal::*;
+pub use reveal::*;
+pub use create_item_pool::*;
+pub use add_pool_items::*;
+pub use mint_from_pool::*;
+pub use set_mint_template::*;
+pub use mint_next::*;
+pub use migrate_accounts::*;
+pub use bubblegum::*;
+pub use mpl_core::*;
 pub use shared::*;
\ No newline at end of file
@@ -0,0 +1,198 @@
+use super::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
+
+/// Metaplex Core program.
+pub const MPL_CORE_ID: Pubkey = pubkey!("CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d");
+
+const CREATE_V1_DISCRIMINATOR: u8 = 0;
+const CREATE_COLLECTION_V1_DISCRIMINATOR: u8 = 1;
+
+// Borsh mirrors of the Metaplex Core instruction arguments this program sends. Only the variants
+// used here are declared; their order must match the Core program's enums.
+
+#[derive(AnchorSerialize)]
+enum CoreDataState {
+    AccountState,
+}
+
+#[derive(AnchorSerialize)]
+enum CoreRuleSet {
+    None,
+}
+
+#[derive(AnchorSerialize)]
+struct CoreCreator {
+    address: Pubkey,
+    percentage: u8,
+}
+
+#[derive(AnchorSerialize)]
+struct CoreRoyalties {
+    basis_points: u16,
+    creators: Vec<CoreCreator>,
+    rule_set: CoreRuleSet,
+}
+
+#[derive(AnchorSerialize)]
+enum CorePlugin {
+    Royalties(CoreRoyalties),
+}
+
+// Leading variants are declared only to keep Core's discriminants.
+#[allow(dead_code)]
+#[derive(AnchorSerialize)]
+enum CorePluginAuthority {
+    None,
+    Owner,
+    UpdateAuthority,
+}
+
+#[derive(AnchorSerialize)]
+struct CorePluginAuthorityPair {
+    plugin: CorePlugin,
+    authority: Option<CorePluginAuthority>,
+}
+
+#[derive(AnchorSerialize)]
+struct CreateCollectionV1Args {
+    name: String,
+    uri: String,
+    plugins: Option<Vec<CorePluginAuthorityPair>>,
+}
+
+#[derive(AnchorSerialize)]
+struct CreateV1Args {
+    data_state: CoreDataState,
+    name: String,
+    uri: String,
+    plugins: Option<Vec<CorePluginAuthorityPair>>,
+}
+
+fn royalties_plugin(seller_fee_basis_points: u16, creators: &[CreatorData]) -> Vec<CorePluginAuthorityPair> {
+    vec![CorePluginAuthorityPair {
+        plugin: CorePlugin::Royalties(CoreRoyalties {
+            basis_points: seller_fee_basis_points,
+            creators: creators
+                .iter()
+                .map(|creator| CoreCreator {
+                    address: creator.address,
+                    percentage: creator.share,
+                })
+                .collect(),
+            rule_set: CoreRuleSet::None,
+        }),
+        authority: Some(CorePluginAuthority::UpdateAuthority),
+    }]
+}
+
+fn instruction_data<T: AnchorSerialize>(discriminator: u8, args: &T) -> Result<Vec<u8>> {
+    let mut data = vec![discriminator];
+    args.serialize(&mut data)?;
+    Ok(data)
+}
+
+/// Accounts shared by the Metaplex Core CPIs, with the program PDA as collection update authority.
+pub struct CoreAccounts<'a, 'info> {
+    pub mpl_core_program: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub authority: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> CoreAccounts<'a, 'info> {
+    /// Creates a Core collection whose royalties plugin applies to every asset in it.
+    pub fn create_collection(
+        &self,
+        collection: &AccountInfo<'info>,
+        signer_seeds: &[&[&[u8]]],
+        name: String,
+        uri: String,
+        seller_fee_basis_points: u16,
+        creators: &[CreatorData],
+    ) -> Result<()> {
+        let args = CreateCollectionV1Args {
+            name,
+            uri,
+            plugins: Some(royalties_plugin(seller_fee_basis_points, creators)),
+        };
+
+        let instruction = Instruction {
+            program_id: MPL_CORE_ID,
+            accounts: vec![
+                AccountMeta::new(collection.key(), true),
+                AccountMeta::new_readonly(self.authority.key(), false),
+                AccountMeta::new(self.payer.key(), true),
+                AccountMeta::new_readonly(self.system_program.key(), false),
+            ],
+            data: instruction_data(CREATE_COLLECTION_V1_DISCRIMINATOR, &args)?,
+        };
+
+        invoke_signed(
+            &instruction,
+            &[
+                collection.clone(),
+                self.authority.clone(),
+                self.payer.clone(),
+                self.system_program.clone(),
+            ],
+            signer_seeds,
+        )?;
+        Ok(())
+    }
+
+    /// Creates a Core asset owned by `owner` inside `collection`, signed by the collection's
+    /// update authority so membership needs no separate verification.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_asset(
+        &self,
+        asset: &AccountInfo<'info>,
+        collection: &AccountInfo<'info>,
+        owner: &AccountInfo<'info>,
+        signer_seeds: &[&[&[u8]]],
+        name: String,
+        uri: String,
+        seller_fee_basis_points: u16,
+        creators: &[CreatorData],
+    ) -> Result<()> {
+        let args = CreateV1Args {
+            data_state: CoreDataState::AccountState,
+            name,
+            uri,
+            plugins: Some(royalties_plugin(seller_fee_basis_points, creators)),
+        };
+
+        // Absent optional accounts (update authority, log wrapper) are passed as the Core program id.
+        let instruction = Instruction {
+            program_id: MPL_CORE_ID,
+            accounts: vec![
+                AccountMeta::new(asset.key(), true),
+                AccountMeta::new(collection.key(), false),
+                AccountMeta::new_readonly(self.authority.key(), true),
+                AccountMeta::new(self.payer.key(), true),
+                AccountMeta::new_readonly(owner.key(), false),
+                AccountMeta::new_readonly(MPL_CORE_ID, false),
+                AccountMeta::new_readonly(self.system_program.key(), false),
+                AccountMeta::new_readonly(MPL_CORE_ID, false),
+            ],
+            data: instruction_data(CREATE_V1_DISCRIMINATOR, &args)?,
+        };
+
+        invoke_signed(
+            &instruction,
+            &[
+                asset.clone(),
+                collection.clone(),
+                self.authority.clone(),
+                self.payer.clone(),
+                owner.clone(),
+                self.mpl_core_program.clone(),
+                self.system_program.clone(),
+            ],
+            signer_seeds,
+        )?;
+        Ok(())
+    }
+}
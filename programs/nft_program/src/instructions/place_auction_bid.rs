@@ -0,0 +1,110 @@
+use super::*;
+
+/// Bids `amount` lamports, escrowed until the auction settles. The bid it outbids is refunded
+/// to `previous_bidder`. English bids landing within `AUCTION_TIME_EXTENSION` of the end push
+/// the end out; a Dutch bid at or above the current price wins outright and ends the auction,
+/// paying only the current price.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PlaceAuctionBid<'info> {
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"auction", auction.mint.as_ref()],
+        bump = auction.bump,
+    )]
+    pub auction: Box<Account<'info, Auction>>,
+
+    #[account(
+        mut,
+        seeds = [b"auction_escrow", auction.key().as_ref()],
+        bump = auction.escrow_bump,
+    )]
+    pub escrow: SystemAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Current top bidder, checked against the auction. Omit while there are no bids.
+    pub previous_bidder: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> PlaceAuctionBid<'info> {
+    fn validate_bid(&self, amount: u64, now: i64) -> Result<()> {
+        if now < self.auction.start_time || now >= self.auction.end_time {
+            return Err(error!(NftError::AuctionNotActive));
+        }
+        if amount < self.auction.minimum_bid(now)? {
+            return Err(error!(NftError::BidTooLow));
+        }
+        if self.previous_bidder.as_ref().map(|bidder| bidder.key()) != self.auction.highest_bidder {
+            return Err(error!(NftError::Unauthorized));
+        }
+        Ok(())
+    }
+}
+
+pub fn place_auction_bid(ctx: Context<PlaceAuctionBid>, amount: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    ctx.accounts.validate_bid(amount, clock.unix_timestamp)?;
+
+    let (amount, end_time) = match ctx.accounts.auction.kind {
+        AuctionKind::English => {
+            let end_time = ctx
+                .accounts
+                .auction
+                .end_time
+                .max(clock.unix_timestamp.saturating_add(AUCTION_TIME_EXTENSION));
+            (amount, end_time)
+        }
+        AuctionKind::Dutch { .. } => (ctx.accounts.auction.minimum_bid(clock.unix_timestamp)?, clock.unix_timestamp),
+    };
+
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.bidder.to_account_info(),
+                to: ctx.accounts.escrow.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    msg!("Bid escrowed!");
+
+    if let Some(previous_bidder) = ctx.accounts.previous_bidder.as_ref() {
+        let auction_key = ctx.accounts.auction.key();
+        let seeds = &[&b"auction_escrow"[..], auction_key.as_ref(), &[ctx.accounts.auction.escrow_bump]];
+        let signer_seeds = &[&seeds[..]];
+
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.escrow.to_account_info(),
+                    to: previous_bidder.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            ctx.accounts.auction.highest_bid,
+        )?;
+        msg!("Previous bid refunded!");
+    }
+
+    let auction = &mut ctx.accounts.auction;
+    auction.highest_bidder = Some(ctx.accounts.bidder.key());
+    auction.highest_bid = amount;
+    auction.end_time = end_time;
+
+    emit_cpi!(AuctionBidPlaced {
+        auction: ctx.accounts.auction.key(),
+        bidder: ctx.accounts.bidder.key(),
+        amount,
+        end_time,
+        placed_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use super::*;
+
+/// Offers `price` lamports for the NFT `target`, or for any verified NFT of the collection
+/// `target` when `collection_wide`. The lamports move into the bid's escrow PDA.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PlaceBid<'info> {
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    /// CHECK: NFT mint or, for collection-wide bids, collection mint the bid is for
+    pub target: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"collection", target.key().as_ref()],
+        bump = collection_info.bump,
+    )]
+    pub collection_info: Option<Box<Account<'info, CollectionInfo>>>,
+
+    #[account(
+        init,
+        payer = bidder,
+        space = Bid::SPACE,
+        seeds = [b"bid", target.key().as_ref(), bidder.key().as_ref()],
+        bump,
+    )]
+    pub bid: Box<Account<'info, Bid>>,
+
+    #[account(
+        mut,
+        seeds = [b"bid_escrow", bid.key().as_ref()],
+        bump,
+    )]
+    pub escrow: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> PlaceBid<'info> {
+    fn validate_bid(&self, price: u64, collection_wide: bool) -> Result<()> {
+        if price == 0 {
+            return Err(error!(NftError::InvalidPrice));
+        }
+        // The escrow holds nothing but `price`, which must keep it rent exempt.
+        if price < Rent::get()?.minimum_balance(0) {
+            return Err(error!(NftError::EscrowBelowRentExempt));
+        }
+        if collection_wide && self.collection_info.is_none() {
+            return Err(error!(NftError::InvalidCollectionMint));
+        }
+        Ok(())
+    }
+}
+
+pub fn place_bid(ctx: Context<PlaceBid>, price: u64, collection_wide: bool) -> Result<()> {
+    ctx.accounts.validate_bid(price, collection_wide)?;
+
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.bidder.to_account_info(),
+                to: ctx.accounts.escrow.to_account_info(),
+            },
+        ),
+        price,
+    )?;
+    msg!("Bid escrowed!");
+
+    let clock = Clock::get()?;
+    let bid = &mut ctx.accounts.bid;
+    bid.bidder = ctx.accounts.bidder.key();
+    bid.target = ctx.accounts.target.key();
+    bid.collection_wide = collection_wide;
+    bid.price = price;
+    bid.created_at = clock.unix_timestamp;
+    bid.bump = ctx.bumps.bid;
+    bid.escrow_bump = ctx.bumps.escrow;
+
+    emit_cpi!(BidPlaced {
+        bid: ctx.accounts.bid.key(),
+        bidder: ctx.accounts.bidder.key(),
+        target: ctx.accounts.target.key(),
+        collection_wide,
+        price,
+        placed_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use super::*;
+
+/// Returns a rented NFT to its owner once the rental expired. Anyone can call it; the rental
+/// agreement's rent goes back to the owner. The owner's token account is empty during the rental
+/// and may have been closed, in which case the caller pays to recreate it.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Reclaim<'info> {
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    #[account(mut)]
+    /// CHECK: Owner recorded on the rental agreement
+    pub owner: UncheckedAccount<'info>,
+
+    /// CHECK: Renter recorded on the rental agreement
+    pub renter: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = caller,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = renter,
+        associated_token::token_program = token_program,
+    )]
+    pub renter_token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Master edition of `mint`, validated by the metaplex program. Omit for Token-2022 NFTs.
+    pub edition: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        seeds = [b"authority"],
+        bump,
+    )]
+    /// CHECK: This account is used for signing purposes only
+    pub mint_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"rental", mint.key().as_ref()],
+        bump = rental_agreement.bump,
+        has_one = owner @ NftError::Unauthorized,
+        has_one = renter @ NftError::Unauthorized,
+    )]
+    pub rental_agreement: Box<Account<'info, RentalAgreement>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
+}
+
+pub fn reclaim(ctx: Context<Reclaim>) -> Result<()> {
+    let clock = Clock::get()?;
+    if clock.unix_timestamp < ctx.accounts.rental_agreement.expires_at {
+        return Err(error!(NftError::RentalNotExpired));
+    }
+
+    let authority_bump = ctx.bumps.mint_authority;
+    let seeds = &[&b"authority"[..], &[authority_bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    let edition = ctx.accounts.edition.as_ref().map(|edition| edition.to_account_info());
+    NftFreezeAccounts {
+        token_program: &ctx.accounts.token_program.to_account_info(),
+        token_metadata_program: &ctx.accounts.token_metadata_program.to_account_info(),
+        token: &ctx.accounts.renter_token.to_account_info(),
+        mint: &ctx.accounts.mint.to_account_info(),
+        edition: edition.as_ref(),
+        owner: &ctx.accounts.renter.to_account_info(),
+        authority: &ctx.accounts.mint_authority.to_account_info(),
+    }
+    .thaw_to(&ctx.accounts.owner_token.to_account_info(), signer_seeds)?;
+    msg!("NFT returned to owner!");
+
+    emit_cpi!(NftRentalReclaimed {
+        mint: ctx.accounts.mint.key(),
+        owner: ctx.accounts.owner.key(),
+        renter: ctx.accounts.renter.key(),
+        reclaimed_by: ctx.accounts.caller.key(),
+        reclaimed_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
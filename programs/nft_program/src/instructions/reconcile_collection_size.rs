@@ -0,0 +1,57 @@
+use super::*;
+
+/// Compares `CollectionInfo.number_of_nfts` with the size Token Metadata keeps in the
+/// collection's `CollectionDetails` and corrects the program's count when they drifted apart.
+/// Token Metadata's size is the reference: it cannot be changed once set, and every verify,
+/// unverify and burn of a member goes through it. Anyone can call it.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ReconcileCollectionSize<'info> {
+    pub collection_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"collection", collection_mint.key().as_ref()],
+        bump = collection_info.bump,
+    )]
+    pub collection_info: Account<'info, CollectionInfo>,
+
+    #[account(
+        constraint = collection_metadata.mint == collection_mint.key() @ NftError::MetadataMintMismatch,
+    )]
+    pub collection_metadata: Account<'info, MetadataAccount>,
+}
+
+impl<'info> ReconcileCollectionSize<'info> {
+    fn metaplex_size(&self) -> Result<u64> {
+        if self.collection_info.standard != CollectionStandard::Metaplex {
+            return Err(error!(NftError::UnsupportedCollectionStandard));
+        }
+        match self.collection_metadata.collection_details {
+            Some(CollectionDetails::V1 { size }) => Ok(size),
+            _ => Err(error!(NftError::UnsizedCollection)),
+        }
+    }
+}
+
+pub fn reconcile_collection_size(ctx: Context<ReconcileCollectionSize>) -> Result<()> {
+    let size = ctx.accounts.metaplex_size()?;
+    let previous = ctx.accounts.collection_info.number_of_nfts;
+
+    if previous == size {
+        msg!("Collection size is in sync: {}", size);
+        return Ok(());
+    }
+
+    ctx.accounts.collection_info.number_of_nfts = size;
+    msg!("Collection count corrected from {} to {}", previous, size);
+
+    emit_cpi!(CollectionSizeReconciled {
+        collection_mint: ctx.accounts.collection_mint.key(),
+        previous,
+        size,
+        reconciled_at: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
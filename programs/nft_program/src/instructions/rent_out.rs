@@ -0,0 +1,146 @@
+use super::*;
+
+/// Lends an NFT to `renter` for `duration` seconds against a lamport `fee`. The NFT moves to the
+/// renter's wallet, where it is frozen with the program PDA as delegate so only `reclaim`
+/// can move it again. Owner and renter both sign.
+///
+/// A delegate on the owner's token account would leave the NFT in the owner's wallet, where
+/// holder checks of token-gated apps cannot see the renter, so the rental moves the token instead.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RentOut<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub renter: Signer<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+        constraint = owner_token.amount == 1 @ NftError::Unauthorized,
+    )]
+    pub owner_token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = renter,
+        associated_token::mint = mint,
+        associated_token::authority = renter,
+        associated_token::token_program = token_program,
+    )]
+    pub renter_token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Master edition of `mint`, validated by the metaplex program. Omit for Token-2022 NFTs.
+    pub edition: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        seeds = [b"authority"],
+        bump,
+    )]
+    /// CHECK: This account is used for signing purposes only
+    pub mint_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = RentalAgreement::SPACE,
+        seeds = [b"rental", mint.key().as_ref()],
+        bump,
+    )]
+    pub rental_agreement: Box<Account<'info, RentalAgreement>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
+}
+
+pub fn rent_out(
+    ctx: Context<RentOut>,
+    duration: i64,
+    fee: u64,
+) -> Result<()> {
+    if duration <= 0 {
+        return Err(error!(NftError::InvalidRentalDuration));
+    }
+    if ctx.accounts.owner_token.is_frozen() {
+        return Err(error!(NftError::AlreadyFrozen));
+    }
+
+    let clock = Clock::get()?;
+    let expires_at = clock
+        .unix_timestamp
+        .checked_add(duration)
+        .ok_or(NftError::NumericalOverflow)?;
+
+    if fee > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.renter.to_account_info(),
+                    to: ctx.accounts.owner.to_account_info(),
+                },
+            ),
+            fee,
+        )?;
+    }
+
+    transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.owner_token.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.renter_token.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        1,
+        ctx.accounts.mint.decimals,
+    )?;
+    msg!("NFT moved to renter!");
+
+    let authority_bump = ctx.bumps.mint_authority;
+    let seeds = &[&b"authority"[..], &[authority_bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    let edition = ctx.accounts.edition.as_ref().map(|edition| edition.to_account_info());
+    NftFreezeAccounts {
+        token_program: &ctx.accounts.token_program.to_account_info(),
+        token_metadata_program: &ctx.accounts.token_metadata_program.to_account_info(),
+        token: &ctx.accounts.renter_token.to_account_info(),
+        mint: &ctx.accounts.mint.to_account_info(),
+        edition: edition.as_ref(),
+        owner: &ctx.accounts.renter.to_account_info(),
+        authority: &ctx.accounts.mint_authority.to_account_info(),
+    }
+    .freeze(signer_seeds)?;
+    msg!("NFT frozen until {}", expires_at);
+
+    let rental_agreement = &mut ctx.accounts.rental_agreement;
+    rental_agreement.mint = ctx.accounts.mint.key();
+    rental_agreement.owner = ctx.accounts.owner.key();
+    rental_agreement.renter = ctx.accounts.renter.key();
+    rental_agreement.fee = fee;
+    rental_agreement.started_at = clock.unix_timestamp;
+    rental_agreement.expires_at = expires_at;
+    rental_agreement.bump = ctx.bumps.rental_agreement;
+
+    emit_cpi!(NftRentedOut {
+        mint: ctx.accounts.mint.key(),
+        owner: ctx.accounts.owner.key(),
+        renter: ctx.accounts.renter.key(),
+        fee,
+        started_at: clock.unix_timestamp,
+        expires_at,
+    });
+
+    Ok(())
+}
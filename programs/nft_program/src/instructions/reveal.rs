@@ -0,0 +1,161 @@
+use super::*;
+
+/// Replaces placeholder URIs with `base_uri` + final index + `.json`, where the final index is
+/// the mint index shifted by the collection's `starting_offset` over the minted supply. NFTs are
+/// passed as writable `remaining_accounts` pairs of `NftInfo` and metadata, so big collections
+/// reveal in batches. The first batch freezes the supply and pins `base_uri`, and the collection
+/// is marked `revealed` once every NFT has been revealed.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(base_uri: String)]
+pub struct Reveal<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub collection_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"collection", collection_mint.key().as_ref()],
+        bump = collection_info.bump,
+        has_one = creator @ NftError::Unauthorized,
+        realloc = CollectionInfo::space(&collection_info.name, &collection_info.symbol, &collection_info.uri)
+            + collection_info.placeholder_uri.len()
+            + base_uri.len(),
+        realloc::payer = creator,
+        realloc::zero = false,
+    )]
+    pub collection_info: Box<Account<'info, CollectionInfo>>,
+
+    #[account(
+        seeds = [b"authority"],
+        bump,
+    )]
+    /// CHECK: This account is used for signing purposes only
+    pub mint_authority: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_metadata_program: Program<'info, Metadata>,
+}
+
+impl<'info> Reveal<'info> {
+    /// Freezes the supply and pins `base_uri` on the first batch, later batches must match it.
+    /// Returns the frozen supply.
+    fn start_reveal(&mut self, base_uri: &str) -> Result<u64> {
+        let collection_info = &mut self.collection_info;
+        if collection_info.placeholder_uri.is_empty() {
+            return Err(error!(NftError::RevealNotConfigured));
+        }
+        match collection_info.reveal_supply {
+            Some(supply) => {
+                if collection_info.base_uri != base_uri {
+                    return Err(error!(NftError::BaseUriMismatch));
+                }
+                Ok(supply)
+            }
+            None => {
+                if collection_info.next_index == 0 {
+                    return Err(error!(NftError::NothingToReveal));
+                }
+                collection_info.reveal_supply = Some(collection_info.next_index);
+                collection_info.base_uri = base_uri.to_string();
+                Ok(collection_info.next_index)
+            }
+        }
+    }
+}
+
+pub fn reveal<'info>(ctx: Context<'_, '_, 'info, 'info, Reveal<'info>>, base_uri: String) -> Result<()> {
+    let supply = ctx.accounts.start_reveal(&base_uri)?;
+    let pairs = ctx.remaining_accounts.chunks_exact(2);
+    if !pairs.remainder().is_empty() {
+        return Err(error!(NftError::InvalidRevealAccounts));
+    }
+
+    let authority_bump = ctx.bumps.mint_authority;
+    let seeds = &[&b"authority"[..], &[authority_bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    let creator = &ctx.accounts.creator.to_account_info();
+    let system_program = &ctx.accounts.system_program.to_account_info();
+    let token_metadata_program = &ctx.accounts.token_metadata_program.to_account_info();
+    let mint_authority = &ctx.accounts.mint_authority.to_account_info();
+
+    let starting_offset = ctx.accounts.collection_info.starting_offset.unwrap_or_default();
+
+    let mut revealed_count = 0u32;
+    for pair in pairs {
+        let (nft_info_account, metadata) = (&pair[0], &pair[1]);
+
+        let mut nft_info = Account::<NftInfo>::try_from(nft_info_account)?;
+        if nft_info.collection_mint != ctx.accounts.collection_mint.key() {
+            return Err(error!(NftError::InvalidCollectionMint));
+        }
+        if metadata.key() != mpl_token_metadata::accounts::Metadata::find_pda(&nft_info.mint).0 {
+            return Err(error!(NftError::MetadataMintMismatch));
+        }
+        if nft_info.revealed {
+            return Err(error!(NftError::NftAlreadyRevealed));
+        }
+        let index = nft_info.index.ok_or(NftError::MissingNftIndex)?;
+
+        let final_index = (index % supply + starting_offset % supply) % supply;
+        let uri = format!("{base_uri}{final_index}.json");
+        validation::validate_uri(&uri)?;
+
+        let current = MetadataAccount::try_deserialize(&mut &metadata.try_borrow_data()?[..])?;
+        UpdateMetadataAccountV2Cpi::new(
+            token_metadata_program,
+            UpdateMetadataAccountV2CpiAccounts {
+                metadata,
+                update_authority: mint_authority,
+            },
+            UpdateMetadataAccountV2InstructionArgs {
+                data: Some(DataV2 {
+                    name: current.name.trim_end_matches('\0').to_string(),
+                    symbol: current.symbol.trim_end_matches('\0').to_string(),
+                    uri: uri.clone(),
+                    seller_fee_basis_points: current.seller_fee_basis_points,
+                    creators: current.creators.clone(),
+                    collection: current.collection.clone(),
+                    uses: current.uses.clone(),
+                }),
+                new_update_authority: None,
+                primary_sale_happened: None,
+                is_mutable: None,
+            },
+        )
+        .invoke_signed(signer_seeds)?;
+
+        resize_with_rent(
+            nft_info_account,
+            creator,
+            system_program,
+            NftInfo::space(&nft_info.name, &nft_info.symbol, &uri),
+        )?;
+        nft_info.uri = uri;
+        nft_info.revealed = true;
+        nft_info.exit(&crate::ID)?;
+
+        revealed_count += 1;
+    }
+    msg!("Revealed {} NFTs!", revealed_count);
+
+    // Items still unrevealed keep their placeholder, so the collection only counts as revealed
+    // once the last one is.
+    let collection_info = &mut ctx.accounts.collection_info;
+    collection_info.revealed_count = collection_info
+        .revealed_count
+        .checked_add(revealed_count as u64)
+        .ok_or(NftError::NumericalOverflow)?;
+    collection_info.revealed = collection_info.revealed_count == supply;
+
+    emit_cpi!(CollectionRevealed {
+        collection_mint: ctx.accounts.collection_mint.key(),
+        base_uri,
+        revealed_count,
+        revealed_at: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,161 @@
+use super::*;
+
+/// Lets the collection creator take back a soulbound NFT, e.g. a revoked credential: the token
+/// is thawed and burned by the program PDA, the delegate approved at mint. The Token Metadata
+/// accounts stay behind, burning them needs the holder's signature, so a verified item is
+/// unverified first and leaves the collection's size.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RevokeSoulbound<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(mut)]
+    /// CHECK: Holder of `token`, receives the rent of the closed accounts
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub metadata: Box<Account<'info, MetadataAccount>>,
+
+    /// CHECK: Master edition of `mint`, validated by the metaplex program
+    pub edition: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"authority"],
+        bump,
+    )]
+    /// CHECK: This account is used for signing purposes only
+    pub mint_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"soulbound", mint.key().as_ref()],
+        bump = soulbound_record.bump,
+    )]
+    pub soulbound_record: Box<Account<'info, SoulboundRecord>>,
+
+    #[account(
+        mut,
+        seeds = [b"collection", soulbound_record.collection_mint.as_ref()],
+        bump = collection_info.bump,
+        has_one = creator @ NftError::Unauthorized,
+    )]
+    pub collection_info: Box<Account<'info, CollectionInfo>>,
+
+    #[account(address = soulbound_record.collection_mint @ NftError::InvalidCollectionMint)]
+    /// CHECK: Collection mint of the NFT, pinned by the soulbound record
+    pub collection_mint: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Metadata of the collection, validated by the metaplex program
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"nft", mint.key().as_ref()],
+        bump = nft_info.bump,
+    )]
+    pub nft_info: Option<Account<'info, NftInfo>>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(address = INSTRUCTIONS_ID)]
+    /// CHECK: Sysvar instruction account that is being checked with an address constraint
+    pub sysvar_instruction: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub token_metadata_program: Program<'info, Metadata>,
+}
+
+impl<'info> RevokeSoulbound<'info> {
+    fn validate_revoke(&self) -> Result<()> {
+        if self.metadata.mint != self.mint.key() {
+            return Err(error!(NftError::MetadataMintMismatch));
+        }
+        Ok(())
+    }
+
+    fn is_verified(&self) -> bool {
+        self.metadata
+            .collection
+            .as_ref()
+            .is_some_and(|collection| collection.verified && collection.key == self.collection_mint.key())
+    }
+}
+
+pub fn revoke_soulbound(ctx: Context<RevokeSoulbound>) -> Result<()> {
+    ctx.accounts.validate_revoke()?;
+
+    let clock = Clock::get()?;
+
+    let authority_bump = ctx.bumps.mint_authority;
+    let seeds = &[&b"authority"[..], &[authority_bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    if ctx.accounts.is_verified() {
+        UnverifyCollectionV1Cpi::new(
+            &ctx.accounts.token_metadata_program.to_account_info(),
+            UnverifyCollectionV1CpiAccounts {
+                authority: &ctx.accounts.mint_authority.to_account_info(),
+                delegate_record: None,
+                metadata: &ctx.accounts.metadata.to_account_info(),
+                collection_mint: &ctx.accounts.collection_mint.to_account_info(),
+                collection_metadata: Some(&ctx.accounts.collection_metadata.to_account_info()),
+                system_program: &ctx.accounts.system_program.to_account_info(),
+                sysvar_instructions: &ctx.accounts.sysvar_instruction.to_account_info(),
+            },
+        )
+        .invoke_signed(signer_seeds)?;
+
+        let collection_info = &mut ctx.accounts.collection_info;
+        collection_info.number_of_nfts = collection_info.number_of_nfts.saturating_sub(1);
+        msg!("Updated collection count to: {}", collection_info.number_of_nfts);
+    }
+
+    let edition = ctx.accounts.edition.to_account_info();
+    NftFreezeAccounts {
+        token_program: &ctx.accounts.token_program.to_account_info(),
+        token_metadata_program: &ctx.accounts.token_metadata_program.to_account_info(),
+        token: &ctx.accounts.token.to_account_info(),
+        mint: &ctx.accounts.mint.to_account_info(),
+        edition: Some(&edition),
+        owner: &ctx.accounts.owner.to_account_info(),
+        authority: &ctx.accounts.mint_authority.to_account_info(),
+    }
+    .thaw_account(signer_seeds)?;
+
+    burn(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.token.to_account_info(),
+                authority: ctx.accounts.mint_authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+    msg!("Soulbound NFT revoked!");
+
+    emit_cpi!(SoulboundRevoked {
+        mint: ctx.accounts.mint.key(),
+        collection_mint: ctx.accounts.soulbound_record.collection_mint,
+        owner: ctx.accounts.owner.key(),
+        revoked_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use super::*;
+
+/// Creates or replaces the template `mint_next` renders the items of a collection from.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetMintTemplate<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub collection_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [b"collection", collection_mint.key().as_ref()],
+        bump = collection_info.bump,
+        has_one = creator @ NftError::Unauthorized,
+    )]
+    pub collection_info: Box<Account<'info, CollectionInfo>>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = MintTemplate::SPACE,
+        seeds = [b"mint_template", collection_mint.key().as_ref()],
+        bump,
+    )]
+    pub mint_template: Box<Account<'info, MintTemplate>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetMintTemplate<'info> {
+    fn validate_template(&self, template: &MintTemplateData) -> Result<()> {
+        if self.collection_info.standard != CollectionStandard::Metaplex {
+            return Err(error!(NftError::UnsupportedCollectionStandard));
+        }
+        if template.uri_suffix.len() > MintTemplateData::MAX_URI_SUFFIX_LENGTH {
+            return Err(error!(NftError::InvalidUri));
+        }
+        validation::validate_seller_fee_basis_points(template.seller_fee_basis_points)?;
+        validation::validate_creators(&template.creators)?;
+
+        // `mint_next` checks every item it renders, this only rejects a template whose very
+        // next item would already be too long.
+        let next_index = self.collection_info.next_index;
+        validation::validate_name(&template.render_name(next_index))?;
+        validation::validate_uri(&template.render_uri(next_index))
+    }
+}
+
+pub fn set_mint_template(ctx: Context<SetMintTemplate>, template: MintTemplateData) -> Result<()> {
+    ctx.accounts.validate_template(&template)?;
+
+    let mint_template = &mut ctx.accounts.mint_template;
+    mint_template.collection_mint = ctx.accounts.collection_mint.key();
+    mint_template.creator = ctx.accounts.creator.key();
+    mint_template.template = template.clone();
+    mint_template.bump = ctx.bumps.mint_template;
+
+    emit_cpi!(MintTemplateSet {
+        collection_mint: ctx.accounts.collection_mint.key(),
+        name_prefix: template.name_prefix,
+        uri_prefix: template.uri_prefix,
+        uri_suffix: template.uri_suffix,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use super::*;
+
+/// Replaces the royalties items of the collection inherit when their `NftData` leaves them out,
+/// and whether items must match them. Items minted earlier keep their metadata.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetRoyaltyPolicy<'info> {
+    pub creator: Signer<'info>,
+
+    /// CHECK: Collection mint, or Core collection, tied to `collection_info` by its seeds
+    pub collection_mint: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"collection", collection_mint.key().as_ref()],
+        bump = collection_info.bump,
+        has_one = creator @ NftError::Unauthorized,
+    )]
+    pub collection_info: Box<Account<'info, CollectionInfo>>,
+}
+
+impl<'info> SetRoyaltyPolicy<'info> {
+    fn validate_royalty_policy(&self, seller_fee_basis_points: u16, creators: &[CreatorData]) -> Result<()> {
+        validation::validate_seller_fee_basis_points(seller_fee_basis_points)?;
+        validation::validate_creators(creators)
+    }
+}
+
+pub fn set_royalty_policy(
+    ctx: Context<SetRoyaltyPolicy>,
+    seller_fee_basis_points: u16,
+    creators: Vec<CreatorData>,
+    enforce: bool,
+) -> Result<()> {
+    ctx.accounts.validate_royalty_policy(seller_fee_basis_points, &creators)?;
+
+    let collection_info = &mut ctx.accounts.collection_info;
+    collection_info.seller_fee_basis_points = seller_fee_basis_points;
+    collection_info.creators = creators.clone();
+    collection_info.enforce_royalties = enforce;
+
+    emit_cpi!(RoyaltyPolicySet {
+        collection_mint: ctx.accounts.collection_mint.key(),
+        seller_fee_basis_points,
+        creators,
+        enforce,
+    });
+
+    Ok(())
+}
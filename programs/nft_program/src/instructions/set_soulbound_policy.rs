@@ -0,0 +1,45 @@
+use super::*;
+
+/// Makes every item of a collection soulbound, or lifts the requirement, before its first mint.
+/// `mint_nft`, `mint_next` and `mint_from_pool` then only mint items with their `SoulboundRecord`,
+/// and the other mint paths reject the collection.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetSoulboundPolicy<'info> {
+    pub creator: Signer<'info>,
+
+    pub collection_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"collection", collection_mint.key().as_ref()],
+        bump = collection_info.bump,
+        has_one = creator @ NftError::Unauthorized,
+    )]
+    pub collection_info: Box<Account<'info, CollectionInfo>>,
+}
+
+impl<'info> SetSoulboundPolicy<'info> {
+    fn validate_soulbound_policy(&self) -> Result<()> {
+        if self.collection_info.standard != CollectionStandard::Metaplex {
+            return Err(error!(NftError::UnsupportedCollectionStandard));
+        }
+        if self.collection_info.next_index > 0 || self.collection_info.compressed_leaf_count > 0 {
+            return Err(error!(NftError::MintingStarted));
+        }
+        Ok(())
+    }
+}
+
+pub fn set_soulbound_policy(ctx: Context<SetSoulboundPolicy>, soulbound: bool) -> Result<()> {
+    ctx.accounts.validate_soulbound_policy()?;
+
+    ctx.accounts.collection_info.soulbound = soulbound;
+
+    emit_cpi!(SoulboundPolicySet {
+        collection_mint: ctx.accounts.collection_mint.key(),
+        soulbound,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use super::*;
+
+/// Approves a wallet to sign `use_nft` for the holder, e.g. an event's check-in wallet, or
+/// revokes the approval with `None`. Unlike a token delegate, a use authority cannot move or burn
+/// the NFT, and its approval lapses once the NFT changes hands.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetUseAuthority<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+        constraint = token.amount == 1 @ NftError::Unauthorized,
+    )]
+    pub token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [b"nft", mint.key().as_ref()],
+        bump = nft_info.bump,
+    )]
+    pub nft_info: Box<Account<'info, NftInfo>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn set_use_authority(ctx: Context<SetUseAuthority>, use_authority: Option<Pubkey>) -> Result<()> {
+    if ctx.accounts.nft_info.uses.is_none() {
+        return Err(error!(NftError::NoUsesRemaining));
+    }
+
+    let owner = ctx.accounts.owner.key();
+    ctx.accounts.nft_info.use_authority = use_authority.map(|authority| UseAuthority { authority, holder: owner });
+
+    emit_cpi!(UseAuthoritySet {
+        mint: ctx.accounts.mint.key(),
+        owner,
+        use_authority,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,184 @@
+use super::*;
+
+/// Closes an ended auction; anyone can call it. The top bid pays royalties to the metadata
+/// creators, passed as writable `remaining_accounts` in metadata order, and the rest to the
+/// seller, and the NFT moves to the winner. Without bids the NFT is thawed in the seller's wallet,
+/// and the PDA delegate is revoked too when the seller settles. Settled by anyone else, the
+/// delegate stays approved, which locks nothing: every lock also freezes the token account or
+/// creates a lock record, and the next freeze approves the delegate anew.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SettleAuction<'info> {
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    #[account(mut)]
+    /// CHECK: Seller recorded on the auction
+    pub seller: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = winner.key() == auction.highest_bidder.unwrap_or(auction.seller) @ NftError::Unauthorized,
+    )]
+    /// CHECK: Top bidder of the auction, or the seller when nobody bid
+    pub winner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = seller,
+        associated_token::token_program = token_program,
+    )]
+    pub seller_token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    /// CHECK: Winner associated token account, created if missing and validated by the associated token program
+    pub winner_token: UncheckedAccount<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub metadata: Box<Account<'info, MetadataAccount>>,
+
+    /// CHECK: Master edition of `mint`, validated by the metaplex program
+    pub edition: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        seeds = [b"authority"],
+        bump,
+    )]
+    /// CHECK: This account is used for signing purposes only
+    pub mint_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"nft", mint.key().as_ref()],
+        bump = nft_info.bump,
+    )]
+    pub nft_info: Option<Account<'info, NftInfo>>,
+
+    #[account(
+        mut,
+        close = seller,
+        seeds = [b"auction", mint.key().as_ref()],
+        bump = auction.bump,
+        has_one = seller @ NftError::Unauthorized,
+    )]
+    pub auction: Box<Account<'info, Auction>>,
+
+    #[account(
+        mut,
+        seeds = [b"auction_escrow", auction.key().as_ref()],
+        bump = auction.escrow_bump,
+    )]
+    pub escrow: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
+}
+
+impl<'info> SettleAuction<'info> {
+    fn validate_settle(&self, now: i64) -> Result<()> {
+        if self.metadata.mint != self.mint.key() {
+            return Err(error!(NftError::MetadataMintMismatch));
+        }
+        if now < self.auction.end_time {
+            return Err(error!(NftError::AuctionNotEnded));
+        }
+        Ok(())
+    }
+}
+
+pub fn settle_auction<'info>(ctx: Context<'_, '_, 'info, 'info, SettleAuction<'info>>) -> Result<()> {
+    let clock = Clock::get()?;
+    ctx.accounts.validate_settle(clock.unix_timestamp)?;
+
+    let authority_bump = ctx.bumps.mint_authority;
+    let seeds = &[&b"authority"[..], &[authority_bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    let edition = ctx.accounts.edition.as_ref().map(|edition| edition.to_account_info());
+    let freeze_accounts = NftFreezeAccounts {
+        token_program: &ctx.accounts.token_program.to_account_info(),
+        token_metadata_program: &ctx.accounts.token_metadata_program.to_account_info(),
+        token: &ctx.accounts.seller_token.to_account_info(),
+        mint: &ctx.accounts.mint.to_account_info(),
+        edition: edition.as_ref(),
+        owner: &ctx.accounts.seller.to_account_info(),
+        authority: &ctx.accounts.mint_authority.to_account_info(),
+    };
+
+    let winner = ctx.accounts.auction.highest_bidder;
+    let price = ctx.accounts.auction.highest_bid;
+    let mut royalties = 0;
+
+    if winner.is_none() {
+        // Revoking needs the seller's signature, which only the seller as caller provides.
+        if ctx.accounts.caller.key() == ctx.accounts.seller.key() {
+            freeze_accounts.thaw(signer_seeds)?;
+        } else {
+            freeze_accounts.thaw_account(signer_seeds)?;
+        }
+        msg!("No bids, NFT thawed for the seller!");
+    } else {
+        let auction_key = ctx.accounts.auction.key();
+        let escrow_seeds = &[&b"auction_escrow"[..], auction_key.as_ref(), &[ctx.accounts.auction.escrow_bump]];
+        let escrow_signer_seeds = &[&escrow_seeds[..]];
+
+        royalties = SalePaymentAccounts {
+            system_program: &ctx.accounts.system_program.to_account_info(),
+            payer: &ctx.accounts.escrow.to_account_info(),
+            seller: &ctx.accounts.seller.to_account_info(),
+            creators: ctx.remaining_accounts,
+        }
+        .pay(&ctx.accounts.metadata, price, escrow_signer_seeds)?;
+        msg!("Sale paid, {} lamports of royalties!", royalties);
+
+        // Anything sent to the escrow on top of the winning bid goes to the seller.
+        let leftover = ctx.accounts.escrow.lamports();
+        if leftover > 0 {
+            transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.escrow.to_account_info(),
+                        to: ctx.accounts.seller.to_account_info(),
+                    },
+                    escrow_signer_seeds,
+                ),
+                leftover,
+            )?;
+        }
+
+        create_idempotent(CpiContext::new(
+            ctx.accounts.associated_token_program.to_account_info(),
+            Create {
+                payer: ctx.accounts.caller.to_account_info(),
+                associated_token: ctx.accounts.winner_token.to_account_info(),
+                authority: ctx.accounts.winner.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+        ))?;
+        freeze_accounts.thaw_to(&ctx.accounts.winner_token.to_account_info(), signer_seeds)?;
+        msg!("NFT delivered to winner!");
+
+        if let Some(nft_info) = ctx.accounts.nft_info.as_mut() {
+            nft_info.owner = ctx.accounts.winner.key();
+        }
+    }
+
+    emit_cpi!(AuctionSettled {
+        auction: ctx.accounts.auction.key(),
+        mint: ctx.accounts.mint.key(),
+        seller: ctx.accounts.seller.key(),
+        winner,
+        price,
+        royalties,
+        settled_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
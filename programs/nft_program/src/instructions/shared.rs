@@ -1,14 +1,652 @@
 use super::*;
 
-pub trait NftUtils {
-    fn string_to_bytes<const N: usize>(&self, s: &str) -> [u8; N] {
-        let mut bytes = [0u8; N];
-        let s_bytes = s.as_bytes();
-        let len = s_bytes.len().min(N);
-        bytes[..len].copy_from_slice(&s_bytes[..len]);
-        bytes
+pub const MAX_NAME_LENGTH: usize = 32;
+pub const MAX_SYMBOL_LENGTH: usize = 10;
+pub const MAX_URI_LENGTH: usize = 200;
+
+/// TLV length of a fixed-size mint extension. `ExtensionType::try_get_tlv_len` is private,
+/// so this is measured as the growth it causes on a mint that already has extensions.
+pub fn mint_extension_tlv_len(extension: ExtensionType) -> Result<usize> {
+    let base = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+        ExtensionType::MetadataPointer,
+    ])?;
+    let extended = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+        ExtensionType::MetadataPointer,
+        extension,
+    ])?;
+    Ok(extended - base)
+}
+
+/// Tops `account` up so it stays rent exempt after token-2022 grows it by `additional_len` bytes.
+pub fn fund_account_growth<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    additional_len: usize,
+) -> Result<()> {
+    let required = Rent::get()?.minimum_balance(account.data_len() + additional_len);
+    let current = account.lamports();
+
+    if required > current {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            required - current,
+        )?;
+    }
+    Ok(())
+}
+
+/// Hash of the newest entry of the slot hashes sysvar, read straight from the account data
+/// (an entry count, then slot and hash pairs, newest first) since the sysvar is too large to
+/// deserialize on chain.
+pub fn recent_slot_hash(slot_hashes: &AccountInfo) -> Result<[u8; 32]> {
+    let data = slot_hashes.try_borrow_data()?;
+    let mut hash = [0u8; 32];
+    hash.copy_from_slice(data.get(16..48).ok_or(ProgramError::InvalidAccountData)?);
+    Ok(hash)
+}
+
+/// Fails unless the running instruction is invoked by the transaction itself, not through CPI,
+/// and is its last instruction, so no program can look at a random draw and revert it.
+pub fn require_isolated_instruction(sysvar_instructions: &AccountInfo) -> Result<()> {
+    use anchor_lang::solana_program::{
+        instruction::{get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT},
+        sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+    };
+
+    if get_stack_height() != TRANSACTION_LEVEL_STACK_HEIGHT {
+        return Err(error!(NftError::DrawNotIsolated));
+    }
+    let current = load_current_index_checked(sysvar_instructions)? as usize;
+    if load_instruction_at_checked(current + 1, sysvar_instructions).is_ok() {
+        return Err(error!(NftError::DrawNotIsolated));
+    }
+    Ok(())
+}
+
+/// Resizes a program account to `new_space`, topping up or refunding rent through `payer`.
+pub fn resize_with_rent<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_space: usize,
+) -> Result<()> {
+    let required = Rent::get()?.minimum_balance(new_space);
+    let current = account.lamports();
+
+    if required > current {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            required - current,
+        )?;
+    } else if current > required {
+        let surplus = current - required;
+        **account.try_borrow_mut_lamports()? -= surplus;
+        **payer.try_borrow_mut_lamports()? += surplus;
+    }
+
+    account.resize(new_space)?;
+    Ok(())
+}
+
+pub struct Token2022MintAccounts<'a, 'info> {
+    pub payer: &'a AccountInfo<'info>,
+    pub mint: &'a AccountInfo<'info>,
+    pub authority: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> Token2022MintAccounts<'a, 'info> {
+    /// Writes name, symbol, uri and `additional_metadata` into the mint's own token-metadata
+    /// extension, with the program PDA as update authority.
+    pub fn initialize_token_metadata(
+        &self,
+        signer_seeds: &[&[&[u8]]],
+        name: String,
+        symbol: String,
+        uri: String,
+        additional_metadata: Vec<(String, String)>,
+    ) -> Result<()> {
+        let token_metadata = TokenMetadata {
+            name: name.clone(),
+            symbol: symbol.clone(),
+            uri: uri.clone(),
+            additional_metadata: additional_metadata.clone(),
+            ..Default::default()
+        };
+        fund_account_growth(self.mint, self.payer, self.system_program, token_metadata.tlv_size_of()?)?;
+
+        token_metadata_initialize(
+            CpiContext::new_with_signer(
+                self.token_program.clone(),
+                TokenMetadataInitialize {
+                    program_id: self.token_program.clone(),
+                    metadata: self.mint.clone(),
+                    update_authority: self.authority.clone(),
+                    mint_authority: self.authority.clone(),
+                    mint: self.mint.clone(),
+                },
+                signer_seeds,
+            ),
+            name,
+            symbol,
+            uri,
+        )?;
+
+        for (key, value) in additional_metadata {
+            token_metadata_update_field(
+                CpiContext::new_with_signer(
+                    self.token_program.clone(),
+                    TokenMetadataUpdateField {
+                        program_id: self.token_program.clone(),
+                        metadata: self.mint.clone(),
+                        update_authority: self.authority.clone(),
+                    },
+                    signer_seeds,
+                ),
+                Field::Key(key),
+                value,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Mints the single token to `destination` and drops the mint authority so supply stays at one.
+    pub fn mint_one_and_lock(&self, destination: &AccountInfo<'info>, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        mint_to(
+            CpiContext::new_with_signer(
+                self.token_program.clone(),
+                MintTo {
+                    mint: self.mint.clone(),
+                    to: destination.clone(),
+                    authority: self.authority.clone(),
+                },
+                signer_seeds,
+            ),
+            1,
+        )?;
+
+        set_authority(
+            CpiContext::new_with_signer(
+                self.token_program.clone(),
+                SetAuthority {
+                    current_authority: self.authority.clone(),
+                    account_or_mint: self.mint.clone(),
+                },
+                signer_seeds,
+            ),
+            AuthorityType::MintTokens,
+            None,
+        )
+    }
+}
+
+/// Accounts to mint a Token Metadata NFT whose mint, metadata and master edition are all
+/// controlled by the program PDA `authority`.
+pub struct MasterEditionMintAccounts<'a, 'info> {
+    pub payer: &'a AccountInfo<'info>,
+    pub mint: &'a AccountInfo<'info>,
+    pub destination: &'a AccountInfo<'info>,
+    pub metadata: &'a AccountInfo<'info>,
+    pub master_edition: &'a AccountInfo<'info>,
+    pub authority: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub token_metadata_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> MasterEditionMintAccounts<'a, 'info> {
+    /// Mints the single token to `destination`, then creates the metadata from `data` and a
+    /// master edition with a max supply of zero.
+    pub fn mint(&self, data: DataV2, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        mint_to(
+            CpiContext::new_with_signer(
+                self.token_program.clone(),
+                MintTo {
+                    mint: self.mint.clone(),
+                    to: self.destination.clone(),
+                    authority: self.authority.clone(),
+                },
+                signer_seeds,
+            ),
+            1,
+        )?;
+
+        CreateMetadataAccountV3Cpi::new(
+            self.token_metadata_program,
+            CreateMetadataAccountV3CpiAccounts {
+                metadata: self.metadata,
+                mint: self.mint,
+                mint_authority: self.authority,
+                payer: self.payer,
+                update_authority: (self.authority, true),
+                system_program: self.system_program,
+                rent: None,
+            },
+            CreateMetadataAccountV3InstructionArgs {
+                data,
+                is_mutable: true,
+                collection_details: None,
+            },
+        )
+        .invoke_signed(signer_seeds)?;
+
+        CreateMasterEditionV3Cpi::new(
+            self.token_metadata_program,
+            CreateMasterEditionV3CpiAccounts {
+                edition: self.master_edition,
+                update_authority: self.authority,
+                mint_authority: self.authority,
+                mint: self.mint,
+                payer: self.payer,
+                metadata: self.metadata,
+                token_program: self.token_program,
+                system_program: self.system_program,
+                rent: None,
+            },
+            CreateMasterEditionV3InstructionArgs {
+                max_supply: Some(0),
+            },
+        )
+        .invoke_signed(signer_seeds)?;
+        Ok(())
+    }
+
+    /// Freezes the freshly minted NFT in `destination`, held by `payer`, to make it soulbound.
+    pub fn freeze_soulbound(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        NftFreezeAccounts {
+            token_program: self.token_program,
+            token_metadata_program: self.token_metadata_program,
+            token: self.destination,
+            mint: self.mint,
+            edition: Some(self.master_edition),
+            owner: self.payer,
+            authority: self.authority,
+        }
+        .freeze(signer_seeds)
+    }
+}
+
+/// Accounts to freeze or thaw an NFT's token account in place with the program PDA.
+///
+/// Freezing approves the PDA as delegate of the token account, signed by `owner`. Token-2022 NFTs
+/// keep the PDA as freeze authority and are frozen directly. Token Metadata NFTs hand freeze
+/// authority to their master edition and freeze through Token Metadata's delegated freeze;
+/// `edition` is required for those.
+pub struct NftFreezeAccounts<'a, 'info> {
+    pub token_program: &'a AccountInfo<'info>,
+    pub token_metadata_program: &'a AccountInfo<'info>,
+    pub token: &'a AccountInfo<'info>,
+    pub mint: &'a AccountInfo<'info>,
+    pub edition: Option<&'a AccountInfo<'info>>,
+    pub owner: &'a AccountInfo<'info>,
+    pub authority: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> NftFreezeAccounts<'a, 'info> {
+    fn is_freeze_authority(&self) -> Result<bool> {
+        let mint = Mint::try_deserialize(&mut &self.mint.try_borrow_data()?[..])?;
+        Ok(mint.freeze_authority == Some(self.authority.key()).into())
+    }
+
+    /// Approves the PDA as delegate and freezes the token account, signed by `owner`. The approval
+    /// replaces any delegate left over, e.g. by a no-bid `settle_auction` run by someone else.
+    pub fn freeze(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        approve(
+            CpiContext::new(
+                self.token_program.clone(),
+                Approve {
+                    to: self.token.clone(),
+                    delegate: self.authority.clone(),
+                    authority: self.owner.clone(),
+                },
+            ),
+            1,
+        )?;
+
+        if self.is_freeze_authority()? {
+            return freeze_account(CpiContext::new_with_signer(
+                self.token_program.clone(),
+                FreezeAccount {
+                    account: self.token.clone(),
+                    mint: self.mint.clone(),
+                    authority: self.authority.clone(),
+                },
+                signer_seeds,
+            ));
+        }
+
+        let edition = self.edition.ok_or(NftError::MissingMasterEdition)?;
+        FreezeDelegatedAccountCpi::new(
+            self.token_metadata_program,
+            FreezeDelegatedAccountCpiAccounts {
+                delegate: self.authority,
+                token_account: self.token,
+                edition,
+                mint: self.mint,
+                token_program: self.token_program,
+            },
+        )
+        .invoke_signed(signer_seeds)?;
+        Ok(())
+    }
+
+    /// Thaws the token account and revokes the PDA delegate, signed by `owner`.
+    pub fn thaw(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        self.thaw_account(signer_seeds)?;
+        revoke(CpiContext::new(
+            self.token_program.clone(),
+            Revoke {
+                source: self.token.clone(),
+                authority: self.owner.clone(),
+            },
+        ))
+    }
+
+    /// Thaws the token account and moves the NFT to `destination` as its delegate, which also
+    /// clears the delegate. Needs no signature from `owner`.
+    pub fn thaw_to(&self, destination: &AccountInfo<'info>, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        self.thaw_account(signer_seeds)?;
+        transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.clone(),
+                TransferChecked {
+                    from: self.token.clone(),
+                    mint: self.mint.clone(),
+                    to: destination.clone(),
+                    authority: self.authority.clone(),
+                },
+                signer_seeds,
+            ),
+            1,
+            0,
+        )
+    }
+
+    /// Thaws the token account only, leaving the PDA delegate approved.
+    pub fn thaw_account(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        if self.is_freeze_authority()? {
+            return thaw_account(CpiContext::new_with_signer(
+                self.token_program.clone(),
+                ThawAccount {
+                    account: self.token.clone(),
+                    mint: self.mint.clone(),
+                    authority: self.authority.clone(),
+                },
+                signer_seeds,
+            ));
+        }
+
+        let edition = self.edition.ok_or(NftError::MissingMasterEdition)?;
+        ThawDelegatedAccountCpi::new(
+            self.token_metadata_program,
+            ThawDelegatedAccountCpiAccounts {
+                delegate: self.authority,
+                token_account: self.token,
+                edition,
+                mint: self.mint,
+                token_program: self.token_program,
+            },
+        )
+        .invoke_signed(signer_seeds)?;
+        Ok(())
+    }
+}
+
+/// Accounts to pay staking rewards out of a `StakingPool`, signed by the pool PDA.
+pub struct RewardPayoutAccounts<'a, 'info> {
+    pub token_program: &'a AccountInfo<'info>,
+    pub reward_mint: &'a AccountInfo<'info>,
+    pub reward_vault: Option<&'a AccountInfo<'info>>,
+    pub destination: &'a AccountInfo<'info>,
+    pub pool: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> RewardPayoutAccounts<'a, 'info> {
+    /// Mints `amount` when the pool is the reward mint's authority, transfers it from the
+    /// reward vault otherwise.
+    pub fn pay(&self, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        let reward_mint = Mint::try_deserialize(&mut &self.reward_mint.try_borrow_data()?[..])?;
+        if reward_mint.mint_authority == Some(self.pool.key()).into() {
+            return mint_to(
+                CpiContext::new_with_signer(
+                    self.token_program.clone(),
+                    MintTo {
+                        mint: self.reward_mint.clone(),
+                        to: self.destination.clone(),
+                        authority: self.pool.clone(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+            );
+        }
+
+        let reward_vault = self.reward_vault.ok_or(NftError::MissingRewardVault)?;
+        transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.clone(),
+                TransferChecked {
+                    from: reward_vault.clone(),
+                    mint: self.reward_mint.clone(),
+                    to: self.destination.clone(),
+                    authority: self.pool.clone(),
+                },
+                signer_seeds,
+            ),
+            amount,
+            reward_mint.decimals,
+        )
+    }
+}
+
+/// Accounts to settle an NFT sale in lamports. `seller_fee_basis_points` of the price go to the
+/// metadata creators, split by share, and the seller receives the rest. `creators` are the
+/// creator wallets in metadata order.
+pub struct SalePaymentAccounts<'a, 'info> {
+    pub system_program: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub seller: &'a AccountInfo<'info>,
+    pub creators: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> SalePaymentAccounts<'a, 'info> {
+    /// Pays `price` out of `payer`, a wallet or a system-owned PDA signing with `signer_seeds`,
+    /// and returns the royalties paid.
+    pub fn pay(&self, metadata: &MetadataAccount, price: u64, signer_seeds: &[&[&[u8]]]) -> Result<u64> {
+        let creators = metadata.creators.as_deref().unwrap_or_default();
+        if creators.len() != self.creators.len()
+            || creators.iter().zip(self.creators).any(|(creator, account)| creator.address != account.key())
+        {
+            return Err(error!(NftError::CreatorMismatch));
+        }
+
+        let royalty_pool = (price as u128)
+            .checked_mul(metadata.seller_fee_basis_points as u128)
+            .ok_or(NftError::NumericalOverflow)?
+            / 10_000;
+
+        let mut royalties = 0u64;
+        for (creator, account) in creators.iter().zip(self.creators) {
+            let amount = (royalty_pool * creator.share as u128 / 100) as u64;
+            self.transfer(account, amount, signer_seeds)?;
+            royalties += amount;
+        }
+
+        let proceeds = price.checked_sub(royalties).ok_or(NftError::NumericalOverflow)?;
+        self.transfer(self.seller, proceeds, signer_seeds)?;
+        Ok(royalties)
+    }
+
+    fn transfer(&self, to: &AccountInfo<'info>, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        transfer(
+            CpiContext::new_with_signer(
+                self.system_program.clone(),
+                Transfer {
+                    from: self.payer.clone(),
+                    to: to.clone(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )
+    }
+}
+
+/// Accounts of a Token Metadata `Transfer` V1, which handles both legacy NFTs and pNFTs
+/// (token records and rule sets are passed for the latter).
+pub struct MetadataTransferAccounts<'a, 'info> {
+    pub token_metadata_program: &'a AccountInfo<'info>,
+    pub token: &'a AccountInfo<'info>,
+    pub token_owner: &'a AccountInfo<'info>,
+    pub destination_token: &'a AccountInfo<'info>,
+    pub destination_owner: &'a AccountInfo<'info>,
+    pub mint: &'a AccountInfo<'info>,
+    pub metadata: &'a AccountInfo<'info>,
+    pub edition: &'a AccountInfo<'info>,
+    pub token_record: Option<&'a AccountInfo<'info>>,
+    pub destination_token_record: Option<&'a AccountInfo<'info>>,
+    pub authority: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub sysvar_instructions: &'a AccountInfo<'info>,
+    pub spl_token_program: &'a AccountInfo<'info>,
+    pub spl_ata_program: &'a AccountInfo<'info>,
+    pub authorization_rules_program: Option<&'a AccountInfo<'info>>,
+    pub authorization_rules: Option<&'a AccountInfo<'info>>,
+}
+
+impl<'a, 'info> MetadataTransferAccounts<'a, 'info> {
+    pub fn transfer(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        TransferV1Cpi::new(
+            self.token_metadata_program,
+            TransferV1CpiAccounts {
+                token: self.token,
+                token_owner: self.token_owner,
+                destination_token: self.destination_token,
+                destination_owner: self.destination_owner,
+                mint: self.mint,
+                metadata: self.metadata,
+                edition: Some(self.edition),
+                token_record: self.token_record,
+                destination_token_record: self.destination_token_record,
+                authority: self.authority,
+                payer: self.payer,
+                system_program: self.system_program,
+                sysvar_instructions: self.sysvar_instructions,
+                spl_token_program: self.spl_token_program,
+                spl_ata_program: self.spl_ata_program,
+                authorization_rules_program: self.authorization_rules_program,
+                authorization_rules: self.authorization_rules,
+            },
+            TransferV1InstructionArgs {
+                amount: 1,
+                authorization_data: None,
+            },
+        )
+        .invoke_signed(signer_seeds)?;
+        Ok(())
+    }
+}
+
+/// Accounts of a Token Metadata `Burn` V1, which closes the token, mint-side accounts and
+/// token record, and shrinks a sized collection when `collection_metadata` is passed.
+pub struct MetadataBurnAccounts<'a, 'info> {
+    pub token_metadata_program: &'a AccountInfo<'info>,
+    pub authority: &'a AccountInfo<'info>,
+    pub collection_metadata: Option<&'a AccountInfo<'info>>,
+    pub metadata: &'a AccountInfo<'info>,
+    pub edition: &'a AccountInfo<'info>,
+    pub mint: &'a AccountInfo<'info>,
+    pub token: &'a AccountInfo<'info>,
+    pub token_record: Option<&'a AccountInfo<'info>>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub sysvar_instructions: &'a AccountInfo<'info>,
+    pub spl_token_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> MetadataBurnAccounts<'a, 'info> {
+    pub fn burn(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        BurnV1Cpi::new(
+            self.token_metadata_program,
+            BurnV1CpiAccounts {
+                authority: self.authority,
+                collection_metadata: self.collection_metadata,
+                metadata: self.metadata,
+                edition: Some(self.edition),
+                mint: self.mint,
+                token: self.token,
+                master_edition: None,
+                master_edition_mint: None,
+                master_edition_token: None,
+                edition_marker: None,
+                token_record: self.token_record,
+                system_program: self.system_program,
+                sysvar_instructions: self.sysvar_instructions,
+                spl_token_program: self.spl_token_program,
+            },
+            BurnV1InstructionArgs { amount: 1 },
+        )
+        .invoke_signed(signer_seeds)?;
+        Ok(())
     }
+}
+
+/// Lock records of an NFT: the stake record, rental agreement, listing, auction and soulbound
+/// record PDAs of its mint. Instructions that freeze, use or burn the NFT in its owner's wallet
+/// pass all five and require that none of them exists.
+pub struct NftLockAccounts<'a, 'info> {
+    pub stake_record: &'a AccountInfo<'info>,
+    pub rental_agreement: &'a AccountInfo<'info>,
+    pub listing: &'a AccountInfo<'info>,
+    pub auction: &'a AccountInfo<'info>,
+    pub soulbound_record: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> NftLockAccounts<'a, 'info> {
+    pub fn require_unlocked(&self, mint: &Pubkey) -> Result<()> {
+        let locks = [
+            (self.stake_record, &b"stake"[..], NftError::NftStaked),
+            (self.rental_agreement, &b"rental"[..], NftError::NftRented),
+            (self.listing, &b"listing"[..], NftError::NftListed),
+            (self.auction, &b"auction"[..], NftError::NftInAuction),
+            (self.soulbound_record, &b"soulbound"[..], NftError::NftSoulbound),
+        ];
+        for (account, prefix, locked) in locks {
+            let (address, _) = Pubkey::find_program_address(&[prefix, mint.as_ref()], &crate::ID);
+            if account.key() != address {
+                return Err(error!(ErrorCode::ConstraintSeeds).with_pubkeys((account.key(), address)));
+            }
+            if !account.data_is_empty() {
+                return Err(error!(locked));
+            }
+        }
+        Ok(())
+    }
+}
 
+pub trait NftUtils {
+    /// Only needed to read the zero-padded strings of legacy accounts.
     fn bytes_to_string<const N: usize>(&self, bytes: &[u8; N]) -> String {
         let end = bytes.iter().position(|&b| b == 0).unwrap_or(N);
         String::from_utf8_lossy(&bytes[..end]).to_string()
@@ -19,19 +657,26 @@ pub mod validation {
     use super::*;
 
     pub fn validate_name(name: &str) -> Result<()> {
-        if name.is_empty() || name.len() > 32 {
+        if name.is_empty() || name.len() > MAX_NAME_LENGTH {
             return Err(error!(NftError::InvalidName));
         }
         Ok(())
     }
 
     pub fn validate_symbol(symbol: &str) -> Result<()> {
-        if symbol.is_empty() || symbol.len() > 10 {
+        if symbol.is_empty() || symbol.len() > MAX_SYMBOL_LENGTH {
             return Err(error!(NftError::InvalidSymbol));
         }
         Ok(())
     }
 
+    pub fn validate_uri(uri: &str) -> Result<()> {
+        if uri.is_empty() || uri.len() > MAX_URI_LENGTH {
+            return Err(error!(NftError::InvalidUri));
+        }
+        Ok(())
+    }
+
     pub fn validate_seller_fee_basis_points(fee: u16) -> Result<()> {
         if fee > 10000 {
             return Err(error!(NftError::InvalidSellerFeeBasisPoints));
@@ -55,12 +700,14 @@ pub mod validation {
     pub trait ValidatableData {
         fn name(&self) -> &str;
         fn symbol(&self) -> &str;
+        fn uri(&self) -> &str;
         fn seller_fee_basis_points(&self) -> u16;
         fn creators(&self) -> &[CreatorData];
 
         fn validate(&self) -> Result<()> {
             validate_name(self.name())?;
             validate_symbol(self.symbol())?;
+            validate_uri(self.uri())?;
             validate_seller_fee_basis_points(self.seller_fee_basis_points())?;
             validate_creators(self.creators())?;
             Ok(())
@@ -68,9 +715,33 @@ pub mod validation {
     }
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
 pub struct CreatorData {
     pub address: Pubkey,
     pub verified: bool,
     pub share: u8,
-}
\ No newline at end of file
+}
+/// How the uses of an NFT are consumed, see Token Metadata's `UseMethod`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum UseMethodData {
+    /// The NFT is burned by its last use.
+    Burn,
+    Multiple,
+    /// Used once, `total` must be 1.
+    Single,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace)]
+pub struct UsesData {
+    pub use_method: UseMethodData,
+    pub total: u64,
+}
+
+impl UsesData {
+    pub fn validate(&self) -> Result<()> {
+        if self.total == 0 || (self.use_method == UseMethodData::Single && self.total != 1) {
+            return Err(error!(NftError::InvalidUses));
+        }
+        Ok(())
+    }
+}
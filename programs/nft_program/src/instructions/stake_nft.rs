@@ -0,0 +1,114 @@
+use super::*;
+
+/// Freezes a verified NFT of the pool's collection in its owner's wallet and starts accruing
+/// rewards for it.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct StakeNFT<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+        constraint = token.amount == 1 @ NftError::Unauthorized,
+    )]
+    pub token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Master edition of `mint`, validated by the metaplex program. Omit for Token-2022 NFTs.
+    pub edition: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        seeds = [b"authority"],
+        bump,
+    )]
+    /// CHECK: This account is used for signing purposes only
+    pub mint_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"nft", mint.key().as_ref()],
+        bump = nft_info.bump,
+        constraint = nft_info.verified @ NftError::NftNotVerified,
+        constraint = nft_info.collection_mint == staking_pool.collection_mint @ NftError::InvalidCollectionMint,
+    )]
+    pub nft_info: Box<Account<'info, NftInfo>>,
+
+    #[account(
+        mut,
+        seeds = [b"staking_pool", staking_pool.collection_mint.as_ref()],
+        bump = staking_pool.bump,
+    )]
+    pub staking_pool: Box<Account<'info, StakingPool>>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = StakeRecord::SPACE,
+        seeds = [b"stake", mint.key().as_ref()],
+        bump,
+    )]
+    pub stake_record: Box<Account<'info, StakeRecord>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub token_metadata_program: Program<'info, Metadata>,
+}
+
+pub fn stake_nft(ctx: Context<StakeNFT>) -> Result<()> {
+    if ctx.accounts.token.is_frozen() {
+        return Err(error!(NftError::AlreadyFrozen));
+    }
+
+    let clock = Clock::get()?;
+
+    let authority_bump = ctx.bumps.mint_authority;
+    let seeds = &[&b"authority"[..], &[authority_bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    let edition = ctx.accounts.edition.as_ref().map(|edition| edition.to_account_info());
+    NftFreezeAccounts {
+        token_program: &ctx.accounts.token_program.to_account_info(),
+        token_metadata_program: &ctx.accounts.token_metadata_program.to_account_info(),
+        token: &ctx.accounts.token.to_account_info(),
+        mint: &ctx.accounts.mint.to_account_info(),
+        edition: edition.as_ref(),
+        owner: &ctx.accounts.owner.to_account_info(),
+        authority: &ctx.accounts.mint_authority.to_account_info(),
+    }
+    .freeze(signer_seeds)?;
+    msg!("NFT frozen for staking!");
+
+    let nft_info = &mut ctx.accounts.nft_info;
+    nft_info.owner = ctx.accounts.owner.key();
+    nft_info.frozen_since = Some(clock.unix_timestamp);
+
+    let stake_record = &mut ctx.accounts.stake_record;
+    stake_record.pool = ctx.accounts.staking_pool.key();
+    stake_record.mint = ctx.accounts.mint.key();
+    stake_record.owner = ctx.accounts.owner.key();
+    stake_record.staked_at = clock.unix_timestamp;
+    stake_record.last_accrued_at = clock.unix_timestamp;
+    stake_record.accrued_points = 0;
+    stake_record.bump = ctx.bumps.stake_record;
+
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    staking_pool.total_staked = staking_pool
+        .total_staked
+        .checked_add(1)
+        .ok_or(NftError::NumericalOverflow)?;
+
+    emit_cpi!(NftStaked {
+        pool: ctx.accounts.staking_pool.key(),
+        mint: ctx.accounts.mint.key(),
+        owner: ctx.accounts.owner.key(),
+        staked_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,127 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct TransferNFT<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Any wallet can receive the NFT
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Recipient associated token account, created by the metaplex program if missing
+    pub recipient_token: UncheckedAccount<'info>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub metadata: Box<Account<'info, MetadataAccount>>,
+
+    /// CHECK: Master edition of `mint`, validated by the metaplex program
+    pub edition: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Owner token record, only passed for pNFTs
+    pub token_record: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    /// CHECK: Recipient token record, only passed for pNFTs
+    pub recipient_token_record: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [b"nft", mint.key().as_ref()],
+        bump = nft_info.bump,
+    )]
+    pub nft_info: Option<Account<'info, NftInfo>>,
+
+    /// CHECK: Rule set of the pNFT, validated by the metaplex program
+    pub authorization_rules: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Token Auth Rules program, validated by the metaplex program
+    pub authorization_rules_program: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(address = INSTRUCTIONS_ID)]
+    /// CHECK: Sysvar instruction account that is being checked with an address constraint
+    pub sysvar_instruction: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
+}
+
+impl<'info> TransferNFT<'info> {
+    fn validate_transfer(&self) -> Result<()> {
+        if self.metadata.mint != self.mint.key() {
+            return Err(error!(NftError::MetadataMintMismatch));
+        }
+        Ok(())
+    }
+}
+
+pub fn transfer_nft(ctx: Context<TransferNFT>) -> Result<()> {
+    ctx.accounts.validate_transfer()?;
+
+    let clock = Clock::get()?;
+
+    let owner = &ctx.accounts.owner.to_account_info();
+    let token_record = ctx.accounts.token_record.as_ref().map(|record| record.to_account_info());
+    let recipient_token_record = ctx
+        .accounts
+        .recipient_token_record
+        .as_ref()
+        .map(|record| record.to_account_info());
+    let authorization_rules = ctx.accounts.authorization_rules.as_ref().map(|rules| rules.to_account_info());
+    let authorization_rules_program = ctx
+        .accounts
+        .authorization_rules_program
+        .as_ref()
+        .map(|program| program.to_account_info());
+
+    MetadataTransferAccounts {
+        token_metadata_program: &ctx.accounts.token_metadata_program.to_account_info(),
+        token: &ctx.accounts.token.to_account_info(),
+        token_owner: owner,
+        destination_token: &ctx.accounts.recipient_token.to_account_info(),
+        destination_owner: &ctx.accounts.recipient.to_account_info(),
+        mint: &ctx.accounts.mint.to_account_info(),
+        metadata: &ctx.accounts.metadata.to_account_info(),
+        edition: &ctx.accounts.edition.to_account_info(),
+        token_record: token_record.as_ref(),
+        destination_token_record: recipient_token_record.as_ref(),
+        authority: owner,
+        payer: owner,
+        system_program: &ctx.accounts.system_program.to_account_info(),
+        sysvar_instructions: &ctx.accounts.sysvar_instruction.to_account_info(),
+        spl_token_program: &ctx.accounts.token_program.to_account_info(),
+        spl_ata_program: &ctx.accounts.associated_token_program.to_account_info(),
+        authorization_rules_program: authorization_rules_program.as_ref(),
+        authorization_rules: authorization_rules.as_ref(),
+    }
+    .transfer(&[])?;
+    msg!("NFT transferred!");
+
+    if let Some(nft_info) = ctx.accounts.nft_info.as_mut() {
+        nft_info.owner = ctx.accounts.recipient.key();
+    }
+
+    emit_cpi!(NftTransferred {
+        mint: ctx.accounts.mint.key(),
+        from: ctx.accounts.owner.key(),
+        to: ctx.accounts.recipient.key(),
+        transferred_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
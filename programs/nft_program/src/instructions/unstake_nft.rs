@@ -0,0 +1,150 @@
+use super::*;
+
+/// Pays out the remaining rewards of a staked NFT, thaws it and closes its stake record.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UnstakeNFT<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Master edition of `mint`, validated by the metaplex program. Omit for Token-2022 NFTs.
+    pub edition: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        seeds = [b"authority"],
+        bump,
+    )]
+    /// CHECK: This account is used for signing purposes only
+    pub mint_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"nft", mint.key().as_ref()],
+        bump = nft_info.bump,
+    )]
+    pub nft_info: Box<Account<'info, NftInfo>>,
+
+    #[account(
+        mut,
+        seeds = [b"staking_pool", staking_pool.collection_mint.as_ref()],
+        bump = staking_pool.bump,
+    )]
+    pub staking_pool: Box<Account<'info, StakingPool>>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"stake", mint.key().as_ref()],
+        bump = stake_record.bump,
+        has_one = owner @ NftError::Unauthorized,
+        constraint = stake_record.pool == staking_pool.key() @ NftError::Unauthorized,
+    )]
+    pub stake_record: Box<Account<'info, StakeRecord>>,
+
+    #[account(
+        mut,
+        address = staking_pool.reward_mint,
+        mint::token_program = reward_token_program,
+    )]
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Omit when the pool mints rewards itself.
+    #[account(
+        mut,
+        associated_token::mint = reward_mint,
+        associated_token::authority = staking_pool,
+        associated_token::token_program = reward_token_program,
+    )]
+    pub reward_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = reward_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = reward_token_program,
+    )]
+    pub reward_destination: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub reward_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
+}
+
+pub fn unstake_nft(ctx: Context<UnstakeNFT>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let stake_record = &mut ctx.accounts.stake_record;
+    stake_record.accrue(ctx.accounts.staking_pool.reward_rate, clock.unix_timestamp)?;
+    let amount = std::mem::take(&mut stake_record.accrued_points);
+
+    let collection_mint = ctx.accounts.staking_pool.collection_mint;
+    let pool_seeds = &[b"staking_pool", collection_mint.as_ref(), &[ctx.accounts.staking_pool.bump]];
+    let pool_signer_seeds = &[&pool_seeds[..]];
+
+    let reward_vault = ctx.accounts.reward_vault.as_ref().map(|vault| vault.to_account_info());
+    RewardPayoutAccounts {
+        token_program: &ctx.accounts.reward_token_program.to_account_info(),
+        reward_mint: &ctx.accounts.reward_mint.to_account_info(),
+        reward_vault: reward_vault.as_ref(),
+        destination: &ctx.accounts.reward_destination.to_account_info(),
+        pool: &ctx.accounts.staking_pool.to_account_info(),
+    }
+    .pay(amount, pool_signer_seeds)?;
+    msg!("Claimed {} reward tokens", amount);
+
+    let authority_bump = ctx.bumps.mint_authority;
+    let seeds = &[&b"authority"[..], &[authority_bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    let edition = ctx.accounts.edition.as_ref().map(|edition| edition.to_account_info());
+    NftFreezeAccounts {
+        token_program: &ctx.accounts.token_program.to_account_info(),
+        token_metadata_program: &ctx.accounts.token_metadata_program.to_account_info(),
+        token: &ctx.accounts.token.to_account_info(),
+        mint: &ctx.accounts.mint.to_account_info(),
+        edition: edition.as_ref(),
+        owner: &ctx.accounts.owner.to_account_info(),
+        authority: &ctx.accounts.mint_authority.to_account_info(),
+    }
+    .thaw(signer_seeds)?;
+    msg!("NFT thawed!");
+
+    ctx.accounts.nft_info.frozen_since = None;
+
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    staking_pool.total_staked = staking_pool.total_staked.saturating_sub(1);
+
+    if amount > 0 {
+        emit_cpi!(RewardsClaimed {
+            pool: ctx.accounts.staking_pool.key(),
+            mint: ctx.accounts.mint.key(),
+            owner: ctx.accounts.owner.key(),
+            amount,
+            claimed_at: clock.unix_timestamp,
+        });
+    }
+
+    emit_cpi!(NftUnstaked {
+        pool: ctx.accounts.staking_pool.key(),
+        mint: ctx.accounts.mint.key(),
+        owner: ctx.accounts.owner.key(),
+        staked_at: ctx.accounts.stake_record.staked_at,
+        unstaked_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,112 @@
+use super::*;
+
+/// Removes a verified NFT from its collection through Token Metadata's sized-collection path,
+/// so the collection's `CollectionDetails` size and `number_of_nfts` both drop by one.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UnverifyCollectionMint<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(mut)]
+    pub metadata: Account<'info, MetadataAccount>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [b"authority"],
+        bump,
+    )]
+    /// CHECK: This account is used for signing purposes only
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// Omit for NFTs minted in lite mode.
+    #[account(
+        mut,
+        seeds = [b"nft", mint.key().as_ref()],
+        bump = nft_info.bump,
+    )]
+    pub nft_info: Option<Account<'info, NftInfo>>,
+
+    pub collection_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"collection", collection_mint.key().as_ref()],
+        bump = collection_info.bump,
+        has_one = creator @ NftError::Unauthorized,
+    )]
+    pub collection_info: Account<'info, CollectionInfo>,
+
+    #[account(mut)]
+    pub collection_metadata: Account<'info, MetadataAccount>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(address = INSTRUCTIONS_ID)]
+    /// CHECK: Sysvar instruction account that is being checked with an address constraint
+    pub sysvar_instruction: UncheckedAccount<'info>,
+
+    pub token_metadata_program: Program<'info, Metadata>,
+}
+
+impl<'info> UnverifyCollectionMint<'info> {
+    fn validate_unverify(&self) -> Result<()> {
+        if self.collection_info.standard != CollectionStandard::Metaplex {
+            return Err(error!(NftError::UnsupportedCollectionStandard));
+        }
+        if self.metadata.mint != self.mint.key() || self.collection_metadata.mint != self.collection_mint.key() {
+            return Err(error!(NftError::MetadataMintMismatch));
+        }
+
+        let collection = self.metadata.collection.as_ref();
+        if collection.map(|collection| collection.key) != Some(self.collection_mint.key()) {
+            return Err(error!(NftError::InvalidCollectionMint));
+        }
+        if !collection.is_some_and(|collection| collection.verified) {
+            return Err(error!(NftError::NftNotVerified));
+        }
+        Ok(())
+    }
+}
+
+pub fn unverify_collection(ctx: Context<UnverifyCollectionMint>) -> Result<()> {
+    ctx.accounts.validate_unverify()?;
+
+    let clock = Clock::get()?;
+
+    let authority_bump = ctx.bumps.mint_authority;
+    let seeds = &[&b"authority"[..], &[authority_bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    UnverifyCollectionV1Cpi::new(
+        &ctx.accounts.token_metadata_program.to_account_info(),
+        UnverifyCollectionV1CpiAccounts {
+            authority: &ctx.accounts.mint_authority.to_account_info(),
+            delegate_record: None,
+            metadata: &ctx.accounts.metadata.to_account_info(),
+            collection_mint: &ctx.accounts.collection_mint.to_account_info(),
+            collection_metadata: Some(&ctx.accounts.collection_metadata.to_account_info()),
+            system_program: &ctx.accounts.system_program.to_account_info(),
+            sysvar_instructions: &ctx.accounts.sysvar_instruction.to_account_info(),
+        },
+    )
+    .invoke_signed(signer_seeds)?;
+    msg!("Collection Unverified!");
+
+    if let Some(nft_info) = ctx.accounts.nft_info.as_mut() {
+        nft_info.verified = false;
+    }
+
+    let collection_info = &mut ctx.accounts.collection_info;
+    collection_info.number_of_nfts = collection_info.number_of_nfts.saturating_sub(1);
+    msg!("Updated collection count to: {}", collection_info.number_of_nfts);
+
+    emit_cpi!(CollectionUnverified {
+        nft_mint: ctx.accounts.mint.key(),
+        collection_mint: ctx.accounts.collection_mint.key(),
+        authority: ctx.accounts.creator.key(),
+        unverified_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
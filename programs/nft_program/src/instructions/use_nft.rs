@@ -0,0 +1,206 @@
+use super::*;
+
+/// Consumes one use of an NFT minted with `uses`. Signed by the holder or by the use authority
+/// the holder approved through `set_use_authority`, e.g. an event's check-in wallet. The last use
+/// of a `Burn` NFT burns it and closes its `NftInfo`, and must be signed by the holder.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UseNFT<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    /// CHECK: Holder of `token`, receives the `NftInfo` rent when the NFT is burned
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+        constraint = token.amount == 1 @ NftError::Unauthorized,
+    )]
+    pub token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub metadata: Box<Account<'info, MetadataAccount>>,
+
+    #[account(mut)]
+    /// CHECK: Master edition of `mint`, validated by the metaplex program
+    pub edition: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"nft", mint.key().as_ref()],
+        bump = nft_info.bump,
+    )]
+    pub nft_info: Box<Account<'info, NftInfo>>,
+
+    #[account(mut)]
+    /// CHECK: Metadata of the collection, validated by the metaplex program. Required with
+    /// `collection_info` when the last use burns a verified item.
+    pub collection_metadata: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [b"collection", collection_info.mint.as_ref()],
+        bump = collection_info.bump,
+    )]
+    pub collection_info: Option<Box<Account<'info, CollectionInfo>>>,
+
+    /// CHECK: Stake record PDA of `mint`, must not exist. Checked by `NftLockAccounts`
+    pub stake_record: UncheckedAccount<'info>,
+
+    /// CHECK: Rental agreement PDA of `mint`, must not exist. Checked by `NftLockAccounts`
+    pub rental_agreement: UncheckedAccount<'info>,
+
+    /// CHECK: Listing PDA of `mint`, must not exist. Checked by `NftLockAccounts`
+    pub listing: UncheckedAccount<'info>,
+
+    /// CHECK: Auction PDA of `mint`, must not exist. Checked by `NftLockAccounts`
+    pub auction: UncheckedAccount<'info>,
+
+    /// CHECK: Soulbound record PDA of `mint`, must not exist. Checked by `NftLockAccounts`
+    pub soulbound_record: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(address = INSTRUCTIONS_ID)]
+    /// CHECK: Sysvar instruction account that is being checked with an address constraint
+    pub sysvar_instruction: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub token_metadata_program: Program<'info, Metadata>,
+}
+
+impl<'info> UseNFT<'info> {
+    fn validate_use(&self) -> Result<UsesData> {
+        if self.metadata.mint != self.mint.key() {
+            return Err(error!(NftError::MetadataMintMismatch));
+        }
+        self.require_unlocked()?;
+
+        let authority = self.authority.key();
+        let approved = self.nft_info.use_authority
+            == Some(UseAuthority { authority, holder: self.owner.key() });
+        if authority != self.owner.key() && !approved {
+            return Err(error!(NftError::Unauthorized));
+        }
+
+        let uses = self.nft_info.uses.ok_or(NftError::NoUsesRemaining)?;
+        if self.nft_info.remaining_uses == 0 {
+            return Err(error!(NftError::NoUsesRemaining));
+        }
+        // The burn is signed by `authority`, and Token Metadata only lets the holder burn.
+        let burns = uses.use_method == UseMethodData::Burn && self.nft_info.remaining_uses == 1;
+        if burns && authority != self.owner.key() {
+            return Err(error!(NftError::HolderMustBurn));
+        }
+        Ok(uses)
+    }
+
+    fn validate_burn(&self) -> Result<()> {
+        if let Some(collection_info) = &self.collection_info {
+            if self.collection_mint() != Some(collection_info.mint) {
+                return Err(error!(NftError::InvalidCollectionMint));
+            }
+        }
+
+        if self.is_verified() && (self.collection_info.is_none() || self.collection_metadata.is_none()) {
+            return Err(error!(NftError::MissingCollectionAccounts));
+        }
+        Ok(())
+    }
+
+    fn require_unlocked(&self) -> Result<()> {
+        NftLockAccounts {
+            stake_record: &self.stake_record.to_account_info(),
+            rental_agreement: &self.rental_agreement.to_account_info(),
+            listing: &self.listing.to_account_info(),
+            auction: &self.auction.to_account_info(),
+            soulbound_record: &self.soulbound_record.to_account_info(),
+        }
+        .require_unlocked(&self.mint.key())
+    }
+
+    fn collection_mint(&self) -> Option<Pubkey> {
+        self.metadata.collection.as_ref().map(|collection| collection.key)
+    }
+
+    fn is_verified(&self) -> bool {
+        self.metadata
+            .collection
+            .as_ref()
+            .is_some_and(|collection| collection.verified)
+    }
+
+    fn burn(&mut self) -> Result<()> {
+        self.validate_burn()?;
+        let was_verified = self.is_verified();
+
+        let collection_metadata = self
+            .collection_metadata
+            .as_ref()
+            .map(|metadata| metadata.to_account_info());
+
+        MetadataBurnAccounts {
+            token_metadata_program: &self.token_metadata_program.to_account_info(),
+            authority: &self.authority.to_account_info(),
+            collection_metadata: collection_metadata.as_ref(),
+            metadata: &self.metadata.to_account_info(),
+            edition: &self.edition.to_account_info(),
+            mint: &self.mint.to_account_info(),
+            token: &self.token.to_account_info(),
+            token_record: None,
+            system_program: &self.system_program.to_account_info(),
+            sysvar_instructions: &self.sysvar_instruction.to_account_info(),
+            spl_token_program: &self.token_program.to_account_info(),
+        }
+        .burn(&[])?;
+        msg!("NFT burned by its last use!");
+
+        if was_verified {
+            if let Some(collection_info) = self.collection_info.as_mut() {
+                collection_info.number_of_nfts = collection_info.number_of_nfts.saturating_sub(1);
+            }
+        }
+
+        self.nft_info.close(self.owner.to_account_info())
+    }
+}
+
+pub fn use_nft(ctx: Context<UseNFT>) -> Result<()> {
+    let uses = ctx.accounts.validate_use()?;
+
+    let clock = Clock::get()?;
+
+    let remaining = ctx.accounts.nft_info.remaining_uses - 1;
+    ctx.accounts.nft_info.remaining_uses = remaining;
+    msg!("Uses remaining: {}", remaining);
+
+    let burned = remaining == 0 && uses.use_method == UseMethodData::Burn;
+    if burned {
+        let collection_mint = ctx.accounts.collection_mint();
+        ctx.accounts.burn()?;
+
+        emit_cpi!(NftBurned {
+            mint: ctx.accounts.mint.key(),
+            collection_mint,
+            owner: ctx.accounts.owner.key(),
+            burned_at: clock.unix_timestamp,
+        });
+    }
+
+    emit_cpi!(NftUsed {
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.authority.key(),
+        remaining,
+        burned,
+        used_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
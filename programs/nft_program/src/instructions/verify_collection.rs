@@ -8,7 +8,7 @@ pub struct VerifyCollectionMint<'info> {
     #[account(mut)]
     pub metadata: Account<'info, MetadataAccount>,
     
-    pub mint: Account<'info, Mint>,
+    pub mint: InterfaceAccount<'info, Mint>,
     
     #[account(
         seeds = [b"authority"],
@@ -17,14 +17,15 @@ pub struct VerifyCollectionMint<'info> {
     /// CHECK: This account is used for signing purposes only
     pub mint_authority: UncheckedAccount<'info>,
 
+    /// Omit for NFTs minted in lite mode, the metadata `collection` field is used instead.
     #[account(
         mut,
         seeds = [b"nft", mint.key().as_ref()],
         bump = nft_info.bump,
     )]
-    pub nft_info: Account<'info, NftInfo>,
+    pub nft_info: Option<Account<'info, NftInfo>>,
     
-    pub collection_mint: Account<'info, Mint>,
+    pub collection_mint: InterfaceAccount<'info, Mint>,
 
     #[account(
         mut,
@@ -51,20 +52,50 @@ impl<'info> NftUtils for VerifyCollectionMint<'info> {}
 
 impl<'info> VerifyCollectionMint<'info> {
     fn validate_collection_relationship(&self) -> Result<()> {
-        if self.nft_info.collection_mint != self.collection_mint.key() {
+        if self.collection_info.standard != CollectionStandard::Metaplex {
+            return Err(error!(NftError::UnsupportedCollectionStandard));
+        }
+
+        if self.metadata.mint != self.mint.key() {
+            return Err(error!(NftError::MetadataMintMismatch));
+        }
+
+        let collection_mint = match &self.nft_info {
+            Some(nft_info) => Some(nft_info.collection_mint),
+            // Without an `NftInfo`, only metadata this program created can vouch for the NFT.
+            None => {
+                if self.metadata.update_authority != self.mint_authority.key() {
+                    return Err(error!(NftError::ForeignNft));
+                }
+                self.metadata.collection.as_ref().map(|collection| collection.key)
+            }
+        };
+        if collection_mint != Some(self.collection_mint.key()) {
             return Err(error!(NftError::InvalidCollectionMint));
         }
 
-        if self.nft_info.verified {
+        if self.is_verified() {
             msg!("NFT is already verified in this collection");
         }
         
         Ok(())
     }
+
+    fn is_verified(&self) -> bool {
+        match &self.nft_info {
+            Some(nft_info) => nft_info.verified,
+            None => self
+                .metadata
+                .collection
+                .as_ref()
+                .is_some_and(|collection| collection.verified),
+        }
+    }
 }
 
 pub fn verify_collection(ctx: Context<VerifyCollectionMint>) -> Result<()> {
     ctx.accounts.validate_collection_relationship()?;
+    let was_verified = ctx.accounts.is_verified();
     
     let clock = Clock::get()?;
 
@@ -100,9 +131,10 @@ pub fn verify_collection(ctx: Context<VerifyCollectionMint>) -> Result<()> {
     
     msg!("Collection Verified!");
 
-    let nft_info = &mut ctx.accounts.nft_info;
-    if !nft_info.verified {
-        nft_info.verified = true;
+    if !was_verified {
+        if let Some(nft_info) = ctx.accounts.nft_info.as_mut() {
+            nft_info.verified = true;
+        }
 
         let collection_info = &mut ctx.accounts.collection_info;
         collection_info.number_of_nfts = collection_info
@@ -1,29 +1,90 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
 pub use anchor_lang::solana_program::sysvar::instructions::ID as INSTRUCTIONS_ID;
+pub use anchor_lang::solana_program::sysvar::slot_hashes::ID as SLOT_HASHES_ID;
+use anchor_lang::solana_program::hash::hashv;
 use anchor_spl::{
-    token::{TokenAccount, Token, Mint, MintTo, mint_to}, 
+    token::Token,
+    token_interface::{
+        Approve,
+        Burn,
+        FreezeAccount,
+        Mint,
+        MintTo,
+        Revoke,
+        SetAuthority,
+        ThawAccount,
+        Token2022,
+        TokenAccount,
+        TokenInterface,
+        TokenMetadataInitialize,
+        TokenMetadataUpdateField,
+        TokenGroupInitialize,
+        TokenMemberInitialize,
+        TransferChecked,
+        approve,
+        burn,
+        freeze_account,
+        mint_to,
+        revoke,
+        set_authority,
+        thaw_account,
+        transfer_checked,
+        spl_token_2022::{self, extension::ExtensionType, instruction::AuthorityType},
+        spl_token_metadata_interface::state::{Field, TokenMetadata},
+        token_metadata_initialize,
+        token_metadata_update_field,
+        token_group_initialize,
+        token_member_initialize,
+    },
     metadata::{
-        MasterEditionAccount, 
+        MasterEditionAccount,
         MetadataAccount,
         Metadata
     },
-    associated_token::AssociatedToken,
+    associated_token::{create_idempotent, AssociatedToken, Create},
     metadata::mpl_token_metadata::{
         self,
         instructions::{
-            CreateMasterEditionV3Cpi, 
-            CreateMasterEditionV3CpiAccounts, 
-            CreateMasterEditionV3InstructionArgs, 
-            CreateMetadataAccountV3Cpi, 
-            CreateMetadataAccountV3CpiAccounts, 
+            BurnV1Cpi,
+            BurnV1CpiAccounts,
+            BurnV1InstructionArgs,
+            CreateMasterEditionV3Cpi,
+            CreateMasterEditionV3CpiAccounts,
+            CreateMasterEditionV3InstructionArgs,
+            CreateMetadataAccountV3Cpi,
+            CreateMetadataAccountV3CpiAccounts,
             CreateMetadataAccountV3InstructionArgs,
+            CreateV1Cpi,
+            CreateV1CpiAccounts,
+            CreateV1InstructionArgs,
+            FreezeDelegatedAccountCpi,
+            FreezeDelegatedAccountCpiAccounts,
+            MintV1Cpi,
+            MintV1CpiAccounts,
+            MintV1InstructionArgs,
+            ThawDelegatedAccountCpi,
+            ThawDelegatedAccountCpiAccounts,
+            TransferV1Cpi,
+            TransferV1CpiAccounts,
+            TransferV1InstructionArgs,
+            UnverifyCollectionV1Cpi,
+            UnverifyCollectionV1CpiAccounts,
+            UpdateMetadataAccountV2Cpi,
+            UpdateMetadataAccountV2CpiAccounts,
+            UpdateMetadataAccountV2InstructionArgs,
             VerifyCollectionV1Cpi,
             VerifyCollectionV1CpiAccounts,
-        }, 
+        },
         types::{
-            Collection,  
-            Creator, 
-            DataV2
+            Collection,
+            CollectionDetails,
+            Creator,
+            DataV2,
+            PrintSupply,
+            TokenStandard,
         }
     }
 };
@@ -55,27 +116,322 @@ security_txt! {
 
 declare_id!("qYcgLKmGgHrREQcgFqVS7WqK35rh3kCXS6mG9T4SMjK");
 
-#[program]
-pub mod nft_program {
+// `#[program]` emits `__idl_resize_account`, which calls the deprecated `AccountInfo::realloc`,
+// next to the program module, so the module is wrapped to scope the allow to its expansion.
+#[allow(deprecated)]
+mod program_entry {
     use super::*;
 
-    pub fn create_collection_instruction(
-        ctx: Context<CreateCollection>,
-        collection_data: CollectionData,
-    ) -> Result<()> {
-        create_collection(ctx, collection_data)
-    }
-    
-    pub fn mint_nft_instruction(
-        ctx: Context<MintNFT>,
-        nft_data: NftData,
-    ) -> Result<()> {
-        mint_nft(ctx, nft_data)
-    }
+    #[program]
+    pub mod nft_program {
+        use super::*;
+
+        pub fn create_collection_instruction(
+            ctx: Context<CreateCollection>,
+            collection_data: CollectionData,
+            provenance_hash: Option<[u8; 32]>,
+        ) -> Result<()> {
+            create_collection(ctx, collection_data, provenance_hash)
+        }
+
+        pub fn create_collection_token_2022_instruction(
+            ctx: Context<CreateCollectionToken2022>,
+            collection_data: CollectionData,
+            max_size: u64,
+        ) -> Result<()> {
+            create_collection_token_2022(ctx, collection_data, max_size)
+        }
+
+        pub fn create_collection_core_instruction(
+            ctx: Context<CreateCollectionCore>,
+            collection_data: CollectionData,
+        ) -> Result<()> {
+            create_collection_core(ctx, collection_data)
+        }
+
+        pub fn create_compressed_tree_instruction(
+            ctx: Context<CreateCompressedTree>,
+            max_depth: u32,
+            max_buffer_size: u32,
+        ) -> Result<()> {
+            create_compressed_tree(ctx, max_depth, max_buffer_size)
+        }
+
+        pub fn mint_nft_instruction(
+            ctx: Context<MintNFT>,
+            nft_data: NftData,
+        ) -> Result<()> {
+            mint_nft(ctx, nft_data)
+        }
+
+        pub fn mint_nft_token_2022_instruction(
+            ctx: Context<MintNFTToken2022>,
+            nft_data: NftData,
+        ) -> Result<()> {
+            mint_nft_token_2022(ctx, nft_data)
+        }
+
+        pub fn mint_nft_core_instruction(
+            ctx: Context<MintNFTCore>,
+            nft_data: NftData,
+        ) -> Result<()> {
+            mint_nft_core(ctx, nft_data)
+        }
+
+        pub fn mint_compressed_nft_instruction(
+            ctx: Context<MintCompressedNFT>,
+            nft_data: NftData,
+        ) -> Result<()> {
+            mint_compressed_nft(ctx, nft_data)
+        }
+
+        pub fn mint_pnft_instruction(
+            ctx: Context<MintPNFT>,
+            nft_data: NftData,
+        ) -> Result<()> {
+            mint_pnft(ctx, nft_data)
+        }
+
+        pub fn verify_collection_instruction(
+            ctx: Context<VerifyCollectionMint>,
+        ) -> Result<()> {
+            verify_collection(ctx)
+        }
+
+        pub fn unverify_collection_instruction(
+            ctx: Context<UnverifyCollectionMint>,
+        ) -> Result<()> {
+            unverify_collection(ctx)
+        }
+
+        pub fn reconcile_collection_size_instruction(
+            ctx: Context<ReconcileCollectionSize>,
+        ) -> Result<()> {
+            reconcile_collection_size(ctx)
+        }
+
+        pub fn set_royalty_policy_instruction(
+            ctx: Context<SetRoyaltyPolicy>,
+            seller_fee_basis_points: u16,
+            creators: Vec<CreatorData>,
+            enforce: bool,
+        ) -> Result<()> {
+            set_royalty_policy(ctx, seller_fee_basis_points, creators, enforce)
+        }
+
+        pub fn set_soulbound_policy_instruction(
+            ctx: Context<SetSoulboundPolicy>,
+            soulbound: bool,
+        ) -> Result<()> {
+            set_soulbound_policy(ctx, soulbound)
+        }
+
+        pub fn transfer_nft_instruction(
+            ctx: Context<TransferNFT>,
+        ) -> Result<()> {
+            transfer_nft(ctx)
+        }
+
+        pub fn burn_nft_instruction(
+            ctx: Context<BurnNFT>,
+        ) -> Result<()> {
+            burn_nft(ctx)
+        }
+
+        pub fn use_nft_instruction(
+            ctx: Context<UseNFT>,
+        ) -> Result<()> {
+            use_nft(ctx)
+        }
+
+        pub fn set_use_authority_instruction(
+            ctx: Context<SetUseAuthority>,
+            use_authority: Option<Pubkey>,
+        ) -> Result<()> {
+            set_use_authority(ctx, use_authority)
+        }
+
+        pub fn revoke_soulbound_instruction(
+            ctx: Context<RevokeSoulbound>,
+        ) -> Result<()> {
+            revoke_soulbound(ctx)
+        }
+
+        pub fn freeze_nft_instruction(
+            ctx: Context<NftFreeze>,
+        ) -> Result<()> {
+            freeze_nft(ctx)
+        }
+
+        pub fn thaw_nft_instruction(
+            ctx: Context<NftFreeze>,
+        ) -> Result<()> {
+            thaw_nft(ctx)
+        }
+
+        pub fn create_staking_pool_instruction(
+            ctx: Context<CreateStakingPool>,
+            reward_rate: u64,
+        ) -> Result<()> {
+            create_staking_pool(ctx, reward_rate)
+        }
+
+        pub fn stake_nft_instruction(
+            ctx: Context<StakeNFT>,
+        ) -> Result<()> {
+            stake_nft(ctx)
+        }
+
+        pub fn unstake_nft_instruction(
+            ctx: Context<UnstakeNFT>,
+        ) -> Result<()> {
+            unstake_nft(ctx)
+        }
+
+        pub fn claim_rewards_instruction(
+            ctx: Context<ClaimRewards>,
+        ) -> Result<()> {
+            claim_rewards(ctx)
+        }
+
+        pub fn rent_out_instruction(
+            ctx: Context<RentOut>,
+            duration: i64,
+            fee: u64,
+        ) -> Result<()> {
+            rent_out(ctx, duration, fee)
+        }
+
+        pub fn reclaim_instruction(
+            ctx: Context<Reclaim>,
+        ) -> Result<()> {
+            reclaim(ctx)
+        }
+
+        pub fn list_nft_instruction(
+            ctx: Context<ListNFT>,
+            price: u64,
+        ) -> Result<()> {
+            list_nft(ctx, price)
+        }
+
+        pub fn cancel_listing_instruction(
+            ctx: Context<CancelListing>,
+        ) -> Result<()> {
+            cancel_listing(ctx)
+        }
+
+        pub fn buy_nft_instruction<'info>(
+            ctx: Context<'_, '_, 'info, 'info, BuyNFT<'info>>,
+            price: u64,
+        ) -> Result<()> {
+            buy_nft(ctx, price)
+        }
+
+        pub fn place_bid_instruction(
+            ctx: Context<PlaceBid>,
+            price: u64,
+            collection_wide: bool,
+        ) -> Result<()> {
+            place_bid(ctx, price, collection_wide)
+        }
+
+        pub fn cancel_bid_instruction(
+            ctx: Context<CancelBid>,
+        ) -> Result<()> {
+            cancel_bid(ctx)
+        }
 
-    pub fn verify_collection_instruction(
-        ctx: Context<VerifyCollectionMint>,
-    ) -> Result<()> {
-        verify_collection(ctx)
+        pub fn accept_bid_instruction<'info>(
+            ctx: Context<'_, '_, 'info, 'info, AcceptBid<'info>>,
+            price: u64,
+        ) -> Result<()> {
+            accept_bid(ctx, price)
+        }
+
+        pub fn create_auction_instruction(
+            ctx: Context<CreateAuction>,
+            params: AuctionParams,
+        ) -> Result<()> {
+            create_auction(ctx, params)
+        }
+
+        pub fn place_auction_bid_instruction(
+            ctx: Context<PlaceAuctionBid>,
+            amount: u64,
+        ) -> Result<()> {
+            place_auction_bid(ctx, amount)
+        }
+
+        pub fn settle_auction_instruction<'info>(
+            ctx: Context<'_, '_, 'info, 'info, SettleAuction<'info>>,
+        ) -> Result<()> {
+            settle_auction(ctx)
+        }
+
+        pub fn configure_reveal_instruction(
+            ctx: Context<ConfigureReveal>,
+            placeholder_uri: String,
+            provenance_hash: [u8; 32],
+        ) -> Result<()> {
+            configure_reveal(ctx, placeholder_uri, provenance_hash)
+        }
+
+        pub fn reveal_instruction<'info>(
+            ctx: Context<'_, '_, 'info, 'info, Reveal<'info>>,
+            base_uri: String,
+        ) -> Result<()> {
+            reveal(ctx, base_uri)
+        }
+
+        pub fn create_item_pool_instruction(
+            ctx: Context<CreateItemPool>,
+            capacity: u32,
+            seller_fee_basis_points: u16,
+            creators: Vec<CreatorData>,
+        ) -> Result<()> {
+            create_item_pool(ctx, capacity, seller_fee_basis_points, creators)
+        }
+
+        pub fn add_pool_items_instruction(
+            ctx: Context<AddPoolItems>,
+            items: Vec<PoolItem>,
+        ) -> Result<()> {
+            add_pool_items(ctx, items)
+        }
+
+        pub fn mint_from_pool_instruction(
+            ctx: Context<MintFromPool>,
+        ) -> Result<()> {
+            mint_from_pool(ctx)
+        }
+
+        pub fn set_mint_template_instruction(
+            ctx: Context<SetMintTemplate>,
+            template: MintTemplateData,
+        ) -> Result<()> {
+            set_mint_template(ctx, template)
+        }
+
+        pub fn mint_next_instruction(
+            ctx: Context<MintNext>,
+        ) -> Result<()> {
+            mint_next(ctx)
+        }
+
+        pub fn migrate_collection_info_instruction(
+            ctx: Context<MigrateCollectionInfo>,
+            next_index: u64,
+        ) -> Result<()> {
+            migrate_collection_info(ctx, next_index)
+        }
+
+        pub fn migrate_nft_info_instruction(
+            ctx: Context<MigrateNftInfo>,
+        ) -> Result<()> {
+            migrate_nft_info(ctx)
+        }
     }
 }
+
+pub use program_entry::*;
@@ -0,0 +1,56 @@
+use super::*;
+
+/// Late bids push `end_time` out to this many seconds after the bid.
+pub const AUCTION_TIME_EXTENSION: i64 = 300;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum AuctionKind {
+    /// Ascending bids, the highest bid standing at `end_time` wins.
+    English,
+    /// The price decays linearly from `start_price` at `start_time` to the reserve price at
+    /// `end_time`; the first bid at the current price wins.
+    Dutch { start_price: u64 },
+}
+
+/// An NFT auctioned off by `seller`. While it exists the NFT stays frozen in the seller's wallet
+/// with the program PDA as delegate, and the top bid sits in a system-owned escrow PDA at
+/// `[b"auction_escrow", auction]`.
+#[derive(InitSpace)]
+#[account]
+pub struct Auction {
+    pub mint: Pubkey,
+    pub seller: Pubkey,
+    pub kind: AuctionKind,
+    pub reserve_price: u64,
+    pub min_increment: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub highest_bidder: Option<Pubkey>,
+    pub highest_bid: u64,
+    pub bump: u8,
+    pub escrow_bump: u8,
+}
+
+impl Auction {
+    pub const SPACE: usize = 8 + Self::INIT_SPACE;
+
+    /// Lowest bid accepted at `now`. A new English bid always beats the highest one, even with
+    /// a zero `min_increment`.
+    pub fn minimum_bid(&self, now: i64) -> Result<u64> {
+        match self.kind {
+            AuctionKind::English => match self.highest_bidder {
+                Some(_) => self
+                    .highest_bid
+                    .checked_add(self.min_increment.max(1))
+                    .ok_or(error!(NftError::NumericalOverflow)),
+                None => Ok(self.reserve_price),
+            },
+            AuctionKind::Dutch { start_price } => {
+                let elapsed = now.saturating_sub(self.start_time).max(0) as u128;
+                let duration = self.end_time.saturating_sub(self.start_time).max(1) as u128;
+                let decay = (start_price - self.reserve_price) as u128 * elapsed.min(duration) / duration;
+                Ok(start_price - decay as u64)
+            }
+        }
+    }
+}
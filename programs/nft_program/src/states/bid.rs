@@ -0,0 +1,20 @@
+use super::*;
+
+/// A lamport offer on one NFT, or on any verified NFT of a collection when `collection_wide`.
+/// `target` is the NFT mint or the collection mint; the offered lamports sit in a system-owned
+/// escrow PDA at `[b"bid_escrow", bid]`.
+#[derive(InitSpace)]
+#[account]
+pub struct Bid {
+    pub bidder: Pubkey,
+    pub target: Pubkey,
+    pub collection_wide: bool,
+    pub price: u64,
+    pub created_at: i64,
+    pub bump: u8,
+    pub escrow_bump: u8,
+}
+
+impl Bid {
+    pub const SPACE: usize = 8 + Self::INIT_SPACE;
+}
@@ -1,14 +1,172 @@
 use super::*;
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum CollectionStandard {
+    /// Metaplex Token Metadata collection, items are verified through `verify_collection`.
+    Metaplex,
+    /// Token-2022 mint carrying the token-group extension, items join it as group members.
+    Token2022Group,
+    /// Metaplex Core collection, items are Core assets created inside it.
+    Core,
+}
+
 #[derive(InitSpace)]
 #[account]
 pub struct CollectionInfo {
+    pub mint: Pubkey,
+    #[max_len(32)]
+    pub name: String,
+    #[max_len(10)]
+    pub symbol: String,
+    #[max_len(200)]
+    pub uri: String,
+    pub creator: Pubkey,
+    pub number_of_nfts: u64,
+    pub created_at: i64,
+    pub bump: u8,
+    pub standard: CollectionStandard,
+    /// Bubblegum Merkle tree compressed NFTs of this collection are minted into.
+    pub merkle_tree: Option<Pubkey>,
+    /// Number of compressed NFTs minted into `merkle_tree`.
+    pub compressed_leaf_count: u64,
+    /// Number of NFTs minted through `mint_nft`, the next one gets it as its `NftInfo.index`.
+    pub next_index: u64,
+    /// URI given to items while the reveal is pending, empty for collections without a reveal.
+    #[max_len(200)]
+    pub placeholder_uri: String,
+    /// Set once every item up to `reveal_supply` has been revealed.
+    pub revealed: bool,
+    /// `next_index` frozen by the first `reveal` batch, the supply final indexes are drawn over.
+    /// Minting through `mint_nft` closes from then on.
+    pub reveal_supply: Option<u64>,
+    /// Prefix of the final URIs, pinned by the first `reveal` batch.
+    #[max_len(200)]
+    pub base_uri: String,
+    /// Items revealed so far out of `reveal_supply`.
+    pub revealed_count: u64,
+    /// Hash of the final metadata, committed to before minting started. Fixed once set.
+    pub provenance_hash: Option<[u8; 32]>,
+    /// Random offset drawn when `provenance_hash` was committed. Item `i` reveals as final item
+    /// `(i + starting_offset) % supply`. Fixed once set.
+    pub starting_offset: Option<u64>,
+    /// Collection this one is a verified member of, e.g. the brand a season belongs to.
+    pub parent: Option<Pubkey>,
+    /// Royalties items inherit when their `NftData` leaves them out.
+    pub seller_fee_basis_points: u16,
+    #[max_len(5)]
+    pub creators: Vec<CreatorData>,
+    /// Rejects items whose royalties differ from the collection defaults.
+    pub enforce_royalties: bool,
+    /// Items must be minted soulbound. Fixed once minting started.
+    pub soulbound: bool,
+}
+
+impl CollectionInfo {
+    /// Account size with the strings stored at their exact length instead of `max_len`, an
+    /// empty `placeholder_uri` and `base_uri`, and room for the maximum number of `creators`.
+    pub fn space(name: &str, symbol: &str, uri: &str) -> usize {
+        8 + Self::INIT_SPACE - (MAX_NAME_LENGTH + MAX_SYMBOL_LENGTH + 3 * MAX_URI_LENGTH)
+            + name.len()
+            + symbol.len()
+            + uri.len()
+    }
+
+    /// Records `provenance_hash` and draws `starting_offset` from it, `slot_hash` and the mint.
+    /// Committing the same hash again is a no-op, a different one is rejected.
+    pub fn commit_provenance(&mut self, provenance_hash: [u8; 32], slot_hash: [u8; 32]) -> Result<()> {
+        match self.provenance_hash {
+            Some(committed) if committed == provenance_hash => return Ok(()),
+            Some(_) => return Err(error!(NftError::ProvenanceAlreadySet)),
+            None => {}
+        }
+
+        let seed = hashv(&[&provenance_hash, &slot_hash, self.mint.as_ref()]).to_bytes();
+        let mut offset = [0u8; 8];
+        offset.copy_from_slice(&seed[..8]);
+
+        self.provenance_hash = Some(provenance_hash);
+        self.starting_offset = Some(u64::from_le_bytes(offset));
+        Ok(())
+    }
+
+    /// Fills the royalties an item left out with the collection defaults, whose creators are
+    /// left unverified, then checks the item against them when `enforce_royalties` is set.
+    /// Items must bring their own creators while the collection has none to inherit.
+    pub fn apply_royalty_policy(
+        &self,
+        seller_fee_basis_points: &mut Option<u16>,
+        creators: &mut Option<Vec<CreatorData>>,
+    ) -> Result<()> {
+        if creators.is_none() && self.creators.is_empty() {
+            return Err(error!(NftError::MissingRoyalties));
+        }
+        let fee = *seller_fee_basis_points.get_or_insert(self.seller_fee_basis_points);
+        let creators = creators.get_or_insert_with(|| {
+            self.creators
+                .iter()
+                .map(|creator| CreatorData { verified: false, ..creator.clone() })
+                .collect()
+        });
+
+        if self.enforce_royalties {
+            let matches = fee == self.seller_fee_basis_points
+                && creators.len() == self.creators.len()
+                && creators
+                    .iter()
+                    .zip(&self.creators)
+                    .all(|(item, collection)| item.address == collection.address && item.share == collection.share);
+            if !matches {
+                return Err(error!(NftError::RoyaltyPolicyViolation));
+            }
+        }
+        Ok(())
+    }
+
+    /// Hands out the next `NftInfo.index`, failing once `reveal` has frozen the supply.
+    pub fn take_next_index(&mut self) -> Result<u64> {
+        if self.reveal_supply.is_some() {
+            return Err(error!(NftError::RevealStarted));
+        }
+        let index = self.next_index;
+        self.next_index = index.checked_add(1).ok_or(NftError::NumericalOverflow)?;
+        Ok(index)
+    }
+
+    /// Fails for an item of a soulbound collection that would be minted without its `SoulboundRecord`.
+    pub fn require_soulbound(&self, soulbound: bool) -> Result<()> {
+        if self.soulbound && !soulbound {
+            return Err(error!(NftError::SoulboundRequired));
+        }
+        Ok(())
+    }
+
+    pub fn reveal_pending(&self) -> bool {
+        !self.placeholder_uri.is_empty() && !self.revealed
+    }
+
+    /// URI an item minted now gets: the placeholder while the reveal is pending, `uri` otherwise.
+    pub fn minted_uri<'a>(&'a self, uri: &'a str) -> &'a str {
+        if self.reveal_pending() {
+            &self.placeholder_uri
+        } else {
+            uri
+        }
+    }
+}
+
+/// Layout of `CollectionInfo` accounts created before strings were stored with their exact length.
+#[derive(AnchorDeserialize)]
+pub struct LegacyCollectionInfo {
     pub mint: Pubkey,
     pub name: [u8; 32],
-    pub symbol: [u8; 10],   
-    pub uri: [u8; 200], 
+    pub symbol: [u8; 10],
+    pub uri: [u8; 200],
     pub creator: Pubkey,
     pub number_of_nfts: u64,
     pub created_at: i64,
     pub bump: u8,
-}
\ No newline at end of file
+}
+
+impl LegacyCollectionInfo {
+    pub const SPACE: usize = 8 + 32 + 32 + 10 + 200 + 32 + 8 + 8 + 1;
+}
@@ -0,0 +1,113 @@
+use super::*;
+
+/// Name and URI of one item uploaded into an `ItemPool`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PoolItem {
+    pub name: String,
+    pub uri: String,
+}
+
+/// Items a collection creator pre-loads for `mint_from_pool` to hand out in random order.
+///
+/// Only this header is (de)serialized by Anchor. It is followed at `HEADER_SPACE` by a bitmap
+/// of minted items, one bit per item of `capacity`, then by the `loaded` items stored at a
+/// fixed `ITEM_SPACE` each, so the pool grows in chunks without deserializing the items.
+#[derive(InitSpace)]
+#[account]
+pub struct ItemPool {
+    pub collection_mint: Pubkey,
+    pub creator: Pubkey,
+    pub capacity: u32,
+    /// Items uploaded so far, minting opens once the pool is full.
+    pub loaded: u32,
+    pub minted: u32,
+    /// Royalties and creators written into the metadata of every minted item.
+    pub seller_fee_basis_points: u16,
+    #[max_len(5)]
+    pub creators: Vec<CreatorData>,
+    pub bump: u8,
+}
+
+impl ItemPool {
+    pub const MAX_CAPACITY: u32 = 10_000;
+    pub const HEADER_SPACE: usize = 8 + Self::INIT_SPACE;
+    /// Length-prefixed name and URI, each padded to its maximum length.
+    pub const ITEM_SPACE: usize = 2 + MAX_NAME_LENGTH + MAX_URI_LENGTH;
+
+    pub fn bitmap_len(capacity: u32) -> usize {
+        (capacity as usize).div_ceil(8)
+    }
+
+    /// Account size holding the header, the bitmap and `loaded` items.
+    pub fn space(capacity: u32, loaded: u32) -> usize {
+        Self::HEADER_SPACE + Self::bitmap_len(capacity) + loaded as usize * Self::ITEM_SPACE
+    }
+
+    fn item_offset(&self, index: u32) -> usize {
+        Self::space(self.capacity, index)
+    }
+
+    /// Writes `item` at `index` into the raw pool account `data`.
+    pub fn write_item(&self, data: &mut [u8], index: u32, item: &PoolItem) -> Result<()> {
+        let offset = self.item_offset(index);
+        let slot = data
+            .get_mut(offset..offset + Self::ITEM_SPACE)
+            .ok_or(NftError::InvalidPoolItem)?;
+        let (name, uri) = slot.split_at_mut(1 + MAX_NAME_LENGTH);
+        write_padded(name, &item.name);
+        write_padded(uri, &item.uri);
+        Ok(())
+    }
+
+    /// Reads the item at `index` from the raw pool account `data`.
+    pub fn read_item(&self, data: &[u8], index: u32) -> Result<PoolItem> {
+        let offset = self.item_offset(index);
+        let slot = data
+            .get(offset..offset + Self::ITEM_SPACE)
+            .ok_or(NftError::InvalidPoolItem)?;
+        let (name, uri) = slot.split_at(1 + MAX_NAME_LENGTH);
+        Ok(PoolItem {
+            name: read_padded(name)?,
+            uri: read_padded(uri)?,
+        })
+    }
+
+    /// Marks the `nth` item not minted yet as minted in the raw pool account `data` and
+    /// returns its index. `nth` must be below `capacity - minted`.
+    pub fn take_unminted(&self, data: &mut [u8], mut nth: u32) -> Result<u32> {
+        let bitmap = data
+            .get_mut(Self::HEADER_SPACE..Self::HEADER_SPACE + Self::bitmap_len(self.capacity))
+            .ok_or(NftError::InvalidPoolItem)?;
+
+        for (byte_index, byte) in bitmap.iter_mut().enumerate() {
+            let free = byte.count_zeros();
+            if nth >= free {
+                nth -= free;
+                continue;
+            }
+            for bit in 0..8 {
+                if *byte & (1 << bit) != 0 {
+                    continue;
+                }
+                if nth == 0 {
+                    *byte |= 1 << bit;
+                    return Ok(byte_index as u32 * 8 + bit);
+                }
+                nth -= 1;
+            }
+        }
+        Err(error!(NftError::PoolEmpty))
+    }
+}
+
+fn write_padded(slot: &mut [u8], value: &str) {
+    slot.fill(0);
+    slot[0] = value.len() as u8;
+    slot[1..1 + value.len()].copy_from_slice(value.as_bytes());
+}
+
+fn read_padded(slot: &[u8]) -> Result<String> {
+    let len = slot[0] as usize;
+    let bytes = slot.get(1..1 + len).ok_or(NftError::InvalidPoolItem)?;
+    String::from_utf8(bytes.to_vec()).map_err(|_| error!(NftError::InvalidPoolItem))
+}
@@ -0,0 +1,17 @@
+use super::*;
+
+/// An NFT offered for sale at a fixed lamport `price`. While it exists the NFT stays frozen in
+/// the seller's wallet with the program PDA as delegate.
+#[derive(InitSpace)]
+#[account]
+pub struct Listing {
+    pub mint: Pubkey,
+    pub seller: Pubkey,
+    pub price: u64,
+    pub listed_at: i64,
+    pub bump: u8,
+}
+
+impl Listing {
+    pub const SPACE: usize = 8 + Self::INIT_SPACE;
+}
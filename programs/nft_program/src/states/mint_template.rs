@@ -0,0 +1,42 @@
+use super::*;
+
+/// Names and URIs `mint_next` renders the items of a collection from: the item numbered `n`
+/// by `CollectionInfo.next_index` is named `{name_prefix}{n}` and points at
+/// `{uri_prefix}{n}{uri_suffix}`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
+pub struct MintTemplateData {
+    #[max_len(32)]
+    pub name_prefix: String,
+    #[max_len(200)]
+    pub uri_prefix: String,
+    #[max_len(16)]
+    pub uri_suffix: String,
+    pub seller_fee_basis_points: u16,
+    #[max_len(5)]
+    pub creators: Vec<CreatorData>,
+}
+
+impl MintTemplateData {
+    pub const MAX_URI_SUFFIX_LENGTH: usize = 16;
+
+    pub fn render_name(&self, number: u64) -> String {
+        format!("{}{number}", self.name_prefix)
+    }
+
+    pub fn render_uri(&self, number: u64) -> String {
+        format!("{}{number}{}", self.uri_prefix, self.uri_suffix)
+    }
+}
+
+#[derive(InitSpace)]
+#[account]
+pub struct MintTemplate {
+    pub collection_mint: Pubkey,
+    pub creator: Pubkey,
+    pub template: MintTemplateData,
+    pub bump: u8,
+}
+
+impl MintTemplate {
+    pub const SPACE: usize = 8 + Self::INIT_SPACE;
+}
@@ -1,7 +1,25 @@
 use super::*;
 
+pub mod auction;
+pub mod bid;
 pub mod collection_info;
+pub mod item_pool;
+pub mod listing;
+pub mod mint_template;
 pub mod nft_info;
+pub mod rental_agreement;
+pub mod soulbound_record;
+pub mod stake_record;
+pub mod staking_pool;
 
+pub use auction::*;
+pub use bid::*;
 pub use collection_info::*;
-pub use nft_info::*;
\ No newline at end of file
+pub use item_pool::*;
+pub use listing::*;
+pub use mint_template::*;
+pub use nft_info::*;
+pub use rental_agreement::*;
+pub use soulbound_record::*;
+pub use stake_record::*;
+pub use staking_pool::*;
@@ -5,11 +5,61 @@ use super::*;
 pub struct NftInfo {
     pub mint: Pubkey,
     pub collection_mint: Pubkey,
-    pub name: [u8; 32],   
-    pub symbol: [u8; 10], 
-    pub uri: [u8; 200],     
+    #[max_len(32)]
+    pub name: String,
+    #[max_len(10)]
+    pub symbol: String,
+    #[max_len(200)]
+    pub uri: String,
     pub owner: Pubkey,
     pub verified: bool,
     pub minted_at: i64,
     pub bump: u8,
+    /// Unix timestamp the NFT was frozen in place at, `None` while it is transferable.
+    pub frozen_since: Option<i64>,
+    /// Position in the collection's mint order, `None` for NFTs not minted through `mint_nft`.
+    pub index: Option<u64>,
+    /// Uses the NFT was minted with through `mint_nft`, consumed by `use_nft`.
+    pub uses: Option<UsesData>,
+    /// Uses left of `uses.total`.
+    pub remaining_uses: u64,
+    /// Set by `reveal` once the NFT points at its final metadata.
+    pub revealed: bool,
+    /// Wallet the holder approved through `set_use_authority` to sign `use_nft`.
+    pub use_authority: Option<UseAuthority>,
+}
+
+/// Use authority approval, which lapses once the NFT is held by anyone but `holder`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct UseAuthority {
+    pub authority: Pubkey,
+    pub holder: Pubkey,
+}
+
+impl NftInfo {
+    /// Account size with the strings stored at their exact length instead of `max_len`.
+    pub fn space(name: &str, symbol: &str, uri: &str) -> usize {
+        8 + Self::INIT_SPACE - (MAX_NAME_LENGTH + MAX_SYMBOL_LENGTH + MAX_URI_LENGTH)
+            + name.len()
+            + symbol.len()
+            + uri.len()
+    }
+}
+
+/// Layout of `NftInfo` accounts created before strings were stored with their exact length.
+#[derive(AnchorDeserialize)]
+pub struct LegacyNftInfo {
+    pub mint: Pubkey,
+    pub collection_mint: Pubkey,
+    pub name: [u8; 32],
+    pub symbol: [u8; 10],
+    pub uri: [u8; 200],
+    pub owner: Pubkey,
+    pub verified: bool,
+    pub minted_at: i64,
+    pub bump: u8,
+}
+
+impl LegacyNftInfo {
+    pub const SPACE: usize = 8 + 32 + 32 + 32 + 10 + 200 + 32 + 1 + 8 + 1;
 }
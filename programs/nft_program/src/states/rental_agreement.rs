@@ -0,0 +1,20 @@
+use super::*;
+
+/// Terms of an NFT lent to `renter` until `expires_at`. While it exists the NFT sits frozen in
+/// the renter's wallet with the program PDA as delegate.
+#[derive(InitSpace)]
+#[account]
+pub struct RentalAgreement {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub renter: Pubkey,
+    /// Lamports the renter paid the owner.
+    pub fee: u64,
+    pub started_at: i64,
+    pub expires_at: i64,
+    pub bump: u8,
+}
+
+impl RentalAgreement {
+    pub const SPACE: usize = 8 + Self::INIT_SPACE;
+}
@@ -0,0 +1,16 @@
+use super::*;
+
+/// Marks an NFT as soulbound: minted frozen in its holder's wallet, with the program PDA as
+/// delegate, and only removed from it by the collection creator through `revoke_soulbound`.
+#[derive(InitSpace)]
+#[account]
+pub struct SoulboundRecord {
+    pub mint: Pubkey,
+    pub collection_mint: Pubkey,
+    pub bound_at: i64,
+    pub bump: u8,
+}
+
+impl SoulboundRecord {
+    pub const SPACE: usize = 8 + Self::INIT_SPACE;
+}
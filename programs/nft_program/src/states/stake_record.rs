@@ -0,0 +1,33 @@
+use super::*;
+
+#[derive(InitSpace)]
+#[account]
+pub struct StakeRecord {
+    pub pool: Pubkey,
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub staked_at: i64,
+    /// Last time `accrued_points` was brought up to date.
+    pub last_accrued_at: i64,
+    /// Rewards earned and not claimed yet, in reward token base units.
+    pub accrued_points: u64,
+    pub bump: u8,
+}
+
+impl StakeRecord {
+    pub const SPACE: usize = 8 + Self::INIT_SPACE;
+
+    /// Adds the rewards earned since `last_accrued_at` at `reward_rate` per second.
+    pub fn accrue(&mut self, reward_rate: u64, now: i64) -> Result<()> {
+        let elapsed = now.saturating_sub(self.last_accrued_at).max(0) as u64;
+        let earned = elapsed
+            .checked_mul(reward_rate)
+            .ok_or(NftError::NumericalOverflow)?;
+        self.accrued_points = self
+            .accrued_points
+            .checked_add(earned)
+            .ok_or(NftError::NumericalOverflow)?;
+        self.last_accrued_at = now;
+        Ok(())
+    }
+}
@@ -0,0 +1,20 @@
+use super::*;
+
+/// Rewards configuration of a collection. Rewards are minted when the pool PDA is the reward
+/// mint's authority and transferred from the pool's reward vault otherwise.
+#[derive(InitSpace)]
+#[account]
+pub struct StakingPool {
+    pub collection_mint: Pubkey,
+    pub authority: Pubkey,
+    pub reward_mint: Pubkey,
+    /// Reward tokens, in base units, accrued per staked NFT per second.
+    pub reward_rate: u64,
+    pub total_staked: u64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl StakingPool {
+    pub const SPACE: usize = 8 + Self::INIT_SPACE;
+}